@@ -4,26 +4,29 @@
 
 venv() {
     setopt localoptions sh_word_split 2>/dev/null || true
-    local subcmd="$1"
+    subcmd="$1"
     if [ $# -gt 0 ]; then shift; fi
     case "$subcmd" in
         -h|--help)
-            printf "Python venv management\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mvenv \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36minit      \\033[0m  Create a new virtual environment\n  \\033[1;36mactivate  \\033[0m  Activate the virtual environment\n  \\033[1;36mdeactivate\\033[0m  Deactivate the virtual environment\n  \\033[1;36mdelete    \\033[0m  Delete the virtual environment\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            printf "Python venv management\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mvenv \\033[0;36m[<subcommand>] [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36minit      \\033[0m  Create a new virtual environment\n  \\033[1;36mactivate  \\033[0m  Activate the virtual environment\n  \\033[1;36mdeactivate\\033[0m  Deactivate the virtual environment\n  \\033[1;36mdelete    \\033[0m  Delete the virtual environment\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
             return
             ;;
+        "")
+            venv activate
+            ;;
         init) _venv_init_ "$@";;
         activate) _venv_activate_ "$@";;
         deactivate) _venv_deactivate_ "$@";;
         delete) _venv_delete_ "$@";;
         -*)
             printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
-            printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv \\033[0;36m[<subcommand>] [-h|--help]\\033[0m\n\n" >&2
             printf "Try 'venv --help' for more information.\n" >&2
             return 1
             ;;
         *)
             printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
-            printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv \\033[0;36m[<subcommand>] [-h|--help]\\033[0m\n\n" >&2
             printf "Try 'venv --help' for more information.\n" >&2
             return 1
             ;;
@@ -53,7 +56,7 @@ _venv_init_() {
     done
 
     # Execute command
-    python -m venv .venv
+    python -m venv ".venv"
     source ".venv/bin/activate"
     echo "Activated virtual environment, version: $(python --version)"
 }
@@ -134,23 +137,23 @@ _venv_delete_() {
 
     # Execute command
     deactivate
-    rm -rf .venv && echo "Removed virtual environment"
+    rm -rf ".venv" && echo "Removed virtual environment"
 }
 
 _completions_venv_() {
     local -a array
     local current=$1; shift
-    local previous=($@)
+    eval 'local previous=($@)'
     case "${previous[@]}" in
-        "venv") array=(
+        "venv") eval 'array=(
             "init:        Create a new virtual environment"
             "activate:    Activate the virtual environment"
             "deactivate:  Deactivate the virtual environment"
             "delete:      Delete the virtual environment"
-            );;
+            )';;
         *) ;;
     esac
-    array+=("-h:         Show help information" "--help:     Show help information")
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
     for elem in "${array[@]}"; do
         if [[ $elem == "$current"* ]]; then echo "$elem"; fi
     done
@@ -158,7 +161,7 @@ _completions_venv_() {
 
 args() {
     setopt localoptions sh_word_split 2>/dev/null || true
-    local subcmd="$1"
+    subcmd="$1"
     if [ $# -gt 0 ]; then shift; fi
     case "$subcmd" in
         -h|--help)
@@ -359,7 +362,7 @@ _args_complex2_() {
 }
 
 _args_val_flags_() {
-    str_flag=""""
+    str_flag=""
     n="42"
     empty_flag=""
     while [ $# -gt 0 ]; do
@@ -414,19 +417,16 @@ _args_val_flags_() {
     done
 
     # Execute command
-    echo "\" --str_flag: [$str_flag] default is \\\"\\\"\""
-    echo "\" --n: [$n] default is 42\""
-    echo "\" --empty_flag: [$empty_flag], default is \\\"\\\"\""
-    if "[" $n -lt 42 "]"
-    then
+echo " --str_flag: [$str_flag] default is \"\""
+echo " --n: [$n] default is 42"
+echo " --empty_flag: [$empty_flag], default is \"\""
+if [ "$n" -lt 42 ]; then
     echo " -n flag is smaller than 42 (n == $n)"
-    elif "[" $n -gt 42 "]"
-    then
+elif [ "$n" -gt 42 ]; then
     echo " -n flag is greater than 42 (n == $n)"
-    elif "[" $n -eq 42 "]"
-    then
+else
     echo " -n flag is equal to 42"
-    fi
+fi
 }
 
 _args_bool_flags_() {
@@ -462,13 +462,13 @@ _args_bool_flags_() {
     done
 
     # Execute command
-    if $a
+    if "$a"
     then
     echo " -a present (a == true)"
     else
     echo " -a absent (a == false)"
     fi
-    if $b
+    if "$b"
     then
     echo " -b is absent (b == true)"
     else
@@ -479,50 +479,50 @@ _args_bool_flags_() {
 _completions_args_() {
     local -a array
     local current=$1; shift
-    local previous=($@)
+    eval 'local previous=($@)'
     case "${previous[@]}" in
-        "args") array=(
+        "args") eval 'array=(
             "two:         Exactly two arguments needed"
             "complex1:    At least 1 args, the last is always arg2"
             "complex2:    At least 1 args, the first is always arg1, unless if it's the only one"
             "val_flags:   Optional flags, can be in any order"
             "bool_flags:  Boolean optional flags"
-            );;
-        "args val_flags") array=(
+            )';;
+        "args val_flags") eval 'array=(
             "--str_flag:    <str_flag> Long name flag"
             "-n:            <n> Short name flag"
             "--empty_flag:  <empty_flag> Default is empty"
-            );;
-        "args bool_flags") array=(
+            )';;
+        "args bool_flags") eval 'array=(
             "-a:          Set to make it true"
             "-b:          Set to make it false"
-            );;
+            )';;
         *) ;;
     esac
-    array+=("-h:         Show help information" "--help:     Show help information")
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
     for elem in "${array[@]}"; do
         if [[ $elem == "$current"* ]]; then echo "$elem"; fi
     done
 }
 
-early_ret_on_error() {
+stop_on_error() {
     setopt localoptions sh_word_split 2>/dev/null || true
     while [ $# -gt 0 ]; do
         case "$1" in
             -h|--help)
-                printf "\\033[1;32mUsage:\\033[0m \\033[1;36mearly_ret_on_error \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36mstop_on_error \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
                 return
                 ;;
             -*)
                 printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
-                printf "\033[1;32mUsage:\033[0m \\033[1;36mearly_ret_on_error \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
-                printf "Try 'early_ret_on_error --help' for more information.\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mstop_on_error \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'stop_on_error --help' for more information.\n" >&2
                 return 1
                 ;;
             *)
                 printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
-                printf "\033[1;32mUsage:\033[0m \\033[1;36mearly_ret_on_error \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
-                printf "Try 'early_ret_on_error --help' for more information.\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mstop_on_error \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'stop_on_error --help' for more information.\n" >&2
                 return 1
                 ;;
         esac
@@ -530,60 +530,60 @@ early_ret_on_error() {
 
     # Execute command
     echo "This command has a & prefix" || return $?
-    $(exit -1) || return $?
-    echo "This command will not run if the previous command fails" || return $?
+    false || return $?
+    echo "This command will not run as the previous command fails" || return $?
 }
 
-_completions_early_ret_on_error_() {
+_completions_stop_on_error_() {
     local -a array
     local current=$1; shift
-    local previous=($@)
+    eval 'local previous=($@)'
     case "${previous[@]}" in
         *) ;;
     esac
-    array+=("-h:         Show help information" "--help:     Show help information")
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
     for elem in "${array[@]}"; do
         if [[ $elem == "$current"* ]]; then echo "$elem"; fi
     done
 }
 
-early_ret_on_ok() {
+stop_on_success() {
     setopt localoptions sh_word_split 2>/dev/null || true
     while [ $# -gt 0 ]; do
         case "$1" in
             -h|--help)
-                printf "\\033[1;32mUsage:\\033[0m \\033[1;36mearly_ret_on_ok \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36mstop_on_success \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
                 return
                 ;;
             -*)
                 printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
-                printf "\033[1;32mUsage:\033[0m \\033[1;36mearly_ret_on_ok \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
-                printf "Try 'early_ret_on_ok --help' for more information.\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mstop_on_success \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'stop_on_success --help' for more information.\n" >&2
                 return 1
                 ;;
             *)
                 printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
-                printf "\033[1;32mUsage:\033[0m \\033[1;36mearly_ret_on_ok \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
-                printf "Try 'early_ret_on_ok --help' for more information.\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mstop_on_success \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'stop_on_success --help' for more information.\n" >&2
                 return 1
                 ;;
         esac
     done
 
     # Execute command
-    $(exit -1) && return 0
+    false && return 0
     echo "This command has a | prefix" && return 0
-    echo "This command will not run if the previous command succeeds" && return 0
+    echo "This command will not run as the previous command succeeds" && return 0
 }
 
-_completions_early_ret_on_ok_() {
+_completions_stop_on_success_() {
     local -a array
     local current=$1; shift
-    local previous=($@)
+    eval 'local previous=($@)'
     case "${previous[@]}" in
         *) ;;
     esac
-    array+=("-h:         Show help information" "--help:     Show help information")
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
     for elem in "${array[@]}"; do
         if [[ $elem == "$current"* ]]; then echo "$elem"; fi
     done
@@ -592,41 +592,34 @@ _completions_early_ret_on_ok_() {
 _complete_bash() {
     local -a raw trimmed
     local IFS=$'\n'
-    local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")
-
-    raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))
-
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
     if (( ${#raw[@]} == 1 )); then
-        trimmed=( "${raw[0]%%:*}" )
-    else
-        trimmed=( "${raw[@]}" )
+        eval 'raw=( "${raw[0]%%:*}" )'
     fi
-
-    COMPREPLY=( "${trimmed[@]}" )
+    eval 'COMPREPLY=( "${raw[@]}" )'
 }
 
 _complete_zsh() {
     local -a raw trimmed
     local IFS=$'\n'
     local previous="${(j: :)words[1,$((CURRENT-1))]}"
-    raw=($("_completions_${words[1]}_" "" "${previous[@]}"))
-
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
     if [ -z "$raw" ]; then
         _default
     else
-        for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
         compadd -V $i -- -d raw -- $trimmed
     fi
 }
-
 if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
     compdef _complete_zsh venv
     compdef _complete_zsh args
-    compdef _complete_zsh early_ret_on_error
-    compdef _complete_zsh early_ret_on_ok
+    compdef _complete_zsh stop_on_error
+    compdef _complete_zsh stop_on_success
 elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
     complete -o default $no_sort -F _complete_bash venv
     complete -o default $no_sort -F _complete_bash args
-    complete -o default $no_sort -F _complete_bash early_ret_on_error
-    complete -o default $no_sort -F _complete_bash early_ret_on_ok
+    complete -o default $no_sort -F _complete_bash stop_on_error
+    complete -o default $no_sort -F _complete_bash stop_on_success
 fi
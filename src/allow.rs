@@ -0,0 +1,82 @@
+use anyhow::Error;
+use eashy::shell_generator::SourceFile;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File listing the allowed project files, one `checksum size path` per line,
+/// as checked by the project hook
+const ALLOWED_FILE: &str = ".eashy/allowed";
+
+/// Allow the project hook to load a project file, or the one of the current
+/// directory, in its current content
+pub fn allow(file: Option<&Path>, quiet: bool) -> Result<(), Error> {
+    let file = match file {
+        Some(file) => file.to_path_buf(),
+        None => find_project_file()?,
+    };
+    // The hook looks for project files from the physical current directory
+    let file = file
+        .canonicalize()
+        .map_err(|e| Error::msg(format!("Failed to find '{}': {}", file.display(), e)))?;
+    let content = fs::read_to_string(&file)
+        .map_err(|e| Error::msg(format!("Failed to read '{}': {}", file.display(), e)))?;
+    let entry = format!(
+        "{} {} {}",
+        SourceFile::new(&file, &content).checksum,
+        content.len(),
+        file.display()
+    );
+
+    let allowed_file = dirs::home_dir()
+        .ok_or_else(|| Error::msg("Failed to find the home directory"))?
+        .join(ALLOWED_FILE);
+    let allowed = fs::read_to_string(&allowed_file).unwrap_or_default();
+    // Only the last allowed content of a file is kept
+    let suffix = format!(" {}", file.display());
+    let mut lines: Vec<&str> = allowed
+        .lines()
+        .filter(|line| !line.ends_with(&suffix))
+        .collect();
+    lines.push(&entry);
+    if let Some(parent) = allowed_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&allowed_file, format!("{}\n", lines.join("\n"))).map_err(|e| {
+        Error::msg(format!(
+            "Failed to write '{}': {}",
+            allowed_file.display(),
+            e
+        ))
+    })?;
+
+    if !quiet {
+        println!("✅ Allowed {}", file.display());
+        println!("   Enter its directory again to load its commands");
+    }
+    Ok(())
+}
+
+/// Find the project file loaded by the hook from the current directory: the
+/// nearest `.eashy.kdl`, or `eashy.kdl` at the root of a git repository
+fn find_project_file() -> Result<PathBuf, Error> {
+    let current_dir = std::env::current_dir()?;
+    current_dir
+        .ancestors()
+        .find_map(|dir| {
+            let hidden = dir.join(".eashy.kdl");
+            let root = dir.join("eashy.kdl");
+            if hidden.is_file() {
+                Some(hidden)
+            } else if dir.join(".git").exists() && root.is_file() {
+                Some(root)
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| {
+            Error::msg(format!(
+                "No .eashy.kdl file found from '{}'",
+                current_dir.display()
+            ))
+        })
+}
@@ -0,0 +1,255 @@
+//! Programmatic construction of commands, as an alternative to KDL
+//!
+//! ```
+//! use eashy::{ArgPrefix, Argument, Command};
+//!
+//! let deploy = Command::builder("deploy")
+//!     .description("Deploy the application")
+//!     .arg(Argument::positional("env").help("Target environment"))
+//!     .arg(Argument::positional("services").prefix(ArgPrefix::ZeroMore))
+//!     .arg(Argument::option("dry_run", false))
+//!     .line("./deploy.sh \"$env\" $services")
+//!     .build()?;
+//! assert_eq!(deploy.get_positional_arguments().len(), 2);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use anyhow::Error;
+use kdl::KdlValue;
+
+use crate::conditions::Condition;
+use crate::parser::{self, ArgPrefix, Argument, Children, CmdPrefix, Command, Example, Hooks};
+
+/// Builder of a [`Command`], created with [`Command::builder`]
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CommandBuilder {
+    name: String,
+    prefix: CmdPrefix,
+    description: Option<String>,
+    arguments: Vec<Argument>,
+    subcommands: Vec<Command>,
+    lines: Vec<String>,
+    requires: Vec<Condition>,
+    hooks: Hooks,
+    cwd: Option<String>,
+    env: Vec<(String, String)>,
+    affects_caller: bool,
+    raw_args: bool,
+    examples: Vec<Example>,
+}
+
+impl Command {
+    /// Start building a command, which has no arguments and an empty body by default
+    pub fn builder(name: impl Into<String>) -> CommandBuilder {
+        CommandBuilder {
+            name: name.into(),
+            prefix: CmdPrefix::None,
+            description: None,
+            arguments: Vec::new(),
+            subcommands: Vec::new(),
+            lines: Vec::new(),
+            requires: Vec::new(),
+            hooks: Hooks::default(),
+            cwd: None,
+            env: Vec::new(),
+            affects_caller: false,
+            raw_args: false,
+            examples: Vec::new(),
+        }
+    }
+}
+
+impl CommandBuilder {
+    /// Set how the body lines are chained
+    pub fn prefix(mut self, prefix: CmdPrefix) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Set the description shown in the help and completion
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a positional argument or an option
+    pub fn arg(mut self, arg: Argument) -> Self {
+        self.arguments.push(arg);
+        self
+    }
+
+    /// Add a subcommand, its path is updated when building
+    pub fn subcommand(mut self, subcommand: Command) -> Self {
+        self.subcommands.push(subcommand);
+        self
+    }
+
+    /// Add a shell line to the body, run when no subcommand is given if the
+    /// command has subcommands
+    pub fn line(mut self, line: impl Into<String>) -> Self {
+        self.lines.push(line.into());
+        self
+    }
+
+    /// Add a condition checked when running the command and its subcommands
+    pub fn requires(mut self, condition: Condition) -> Self {
+        self.requires.push(condition);
+        self
+    }
+
+    /// Set the shell lines run around the body of the command and of its subcommands
+    pub fn hooks(mut self, hooks: Hooks) -> Self {
+        self.hooks = hooks;
+        self
+    }
+
+    /// Set the directory the body runs in, `git-root` being the root of the
+    /// current git repository, and relative ones relative to the current
+    /// directory
+    pub fn cwd(mut self, dir: impl Into<String>) -> Self {
+        self.cwd = Some(dir.into());
+        self
+    }
+
+    /// Add an environment variable exported for the body, its value being
+    /// expanded by the shell
+    pub fn env(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set whether the body runs in the caller's shell, instead of a subshell
+    pub fn affects_caller(mut self, affects_caller: bool) -> Self {
+        self.affects_caller = affects_caller;
+        self
+    }
+
+    /// Set whether every argument is given to the body as is, in `"$@"`,
+    /// instead of being parsed
+    pub fn raw_args(mut self, raw_args: bool) -> Self {
+        self.raw_args = raw_args;
+        self
+    }
+
+    /// Add an example invocation, checked by `eashy test`
+    pub fn example(mut self, example: Example) -> Self {
+        self.examples.push(example);
+        self
+    }
+
+    /// Build the command, validated like the ones parsed from KDL
+    pub fn build(self) -> Result<Command, Error> {
+        let (children, default_body) = if self.subcommands.is_empty() {
+            (Children::Body(self.lines), Vec::new())
+        } else {
+            (Children::Subcmds(self.subcommands), self.lines)
+        };
+
+        let mut command = Command {
+            path: vec![self.name.clone()],
+            name: self.name,
+            prefix: self.prefix,
+            description: self.description,
+            arguments: self.arguments,
+            children,
+            default_body,
+            requires: self.requires,
+            hooks: self.hooks,
+            cwd: self.cwd,
+            env: self.env,
+            affects_caller: self.affects_caller,
+            raw_args: self.raw_args,
+            examples: self.examples,
+            location: None,
+        };
+        command.set_parent_path(&[]);
+        command.resolve_cwd(None);
+        parser::validate(std::slice::from_ref(&command))?;
+        Ok(command)
+    }
+}
+
+impl Command {
+    /// Update the path of the command and its subcommands, from the one of its parent
+    pub(crate) fn set_parent_path(&mut self, parent_path: &[String]) {
+        self.path = parent_path.to_vec();
+        self.path.push(self.name.clone());
+        if let Children::Subcmds(subcommands) = &mut self.children {
+            for subcommand in subcommands {
+                subcommand.set_parent_path(&self.path);
+            }
+        }
+    }
+}
+
+impl Argument {
+    /// Create a positional argument taking exactly one value
+    pub fn positional(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Argument {
+            help: name.to_uppercase(),
+            name,
+            prefix: ArgPrefix::None,
+            option: None,
+        }
+    }
+
+    /// Create an option with its default value, a boolean making it a flag
+    pub fn option(name: impl Into<String>, default: impl Into<KdlValue>) -> Self {
+        let name = name.into();
+        Argument {
+            help: name.to_uppercase(),
+            name,
+            prefix: ArgPrefix::ZeroOne,
+            option: Some(default.into()),
+        }
+    }
+
+    /// Set the description shown in the help
+    #[must_use]
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = help.into();
+        self
+    }
+
+    /// Set how many values a positional argument takes
+    #[must_use]
+    pub fn prefix(mut self, prefix: ArgPrefix) -> Self {
+        self.prefix = prefix;
+        self
+    }
+}
+
+impl Example {
+    /// Create an example invocation with its arguments, expected to succeed
+    pub fn new(args: impl Into<String>) -> Self {
+        Example {
+            args: args.into(),
+            stdout: None,
+            stderr: None,
+            status: 0,
+        }
+    }
+
+    /// Set a text expected in the standard output
+    #[must_use]
+    pub fn stdout(mut self, stdout: impl Into<String>) -> Self {
+        self.stdout = Some(stdout.into());
+        self
+    }
+
+    /// Set a text expected in the standard error
+    #[must_use]
+    pub fn stderr(mut self, stderr: impl Into<String>) -> Self {
+        self.stderr = Some(stderr.into());
+        self
+    }
+
+    /// Set the expected exit status
+    #[must_use]
+    pub fn status(mut self, status: i32) -> Self {
+        self.status = status;
+        self
+    }
+}
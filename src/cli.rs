@@ -1,10 +1,12 @@
 use anyhow::Error;
-use clap::Parser;
 use clap::builder::Styles;
 use clap::builder::styling::{AnsiColor, Effects, Style};
+use clap::{Parser, Subcommand, ValueEnum};
+use eashy::Format;
 use std::path::{Path, PathBuf};
 
 const FOLDER_DIR: &str = ".eashy";
+const COMMANDS_DIR: &str = "commands.d";
 
 /// Expand ~ in paths to the home directory
 fn expand_tilde(path: PathBuf) -> PathBuf {
@@ -40,22 +42,131 @@ const CARGO_STYLING: Styles = Styles::styled()
 #[command(version)]
 #[command(styles = CARGO_STYLING)]
 pub struct Cli {
-    /// KDL file to parse
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+
+    /// KDL, JSON, YAML or TOML file, or directory of such files, to parse
+    /// [default: ~/.eashy/commands.d/ if it exists, else ~/.eashy/default.kdl]
+    #[arg(short, long, value_name = "PATH", global = true)]
+    pub file: Option<PathBuf>,
+
+    /// Output shell script file (use "-" for stdout)
     #[arg(
         short,
         long,
         value_name = "FILE",
-        default_value = "~/.eashy/default.kdl"
+        default_value = "~/.eashy/eashy.sh",
+        global = true
     )]
-    pub file: Option<PathBuf>,
-
-    /// Output shell script file (use "-" for stdout)
-    #[arg(short, long, value_name = "FILE", default_value = "~/.eashy/eashy.sh")]
     pub output: Option<String>,
 
     /// Suppress messages and sourcing instructions
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     pub quiet: bool,
+
+    /// Load project commands (.eashy.kdl) when entering a directory
+    #[arg(short, long, global = true)]
+    pub project: bool,
+
+    /// Regenerate and source the script again from the prompt when a KDL file changes
+    #[arg(short, long, global = true)]
+    pub reload: bool,
+
+    /// Keep generating the other files when a KDL file fails to parse
+    #[arg(short, long, global = true)]
+    pub keep_going: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Commands {
+    /// Generate the shell script (default when no command is given)
+    Generate,
+    /// Parse and validate the KDL files, without generating anything
+    Check,
+    /// List every command and subcommand with its description
+    List,
+    /// Show the help of a command
+    Show {
+        /// Command path, like "venv init"
+        #[arg(required = true)]
+        path: Vec<String>,
+    },
+    /// Write a default KDL file to start from, if missing
+    Init,
+    /// Source the generated script from the RC file of each detected shell
+    Install {
+        /// Print the changes to the RC files without writing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Let the project hook load a project file, in its current content
+    Allow {
+        /// Project file [default: the one loaded from the current directory]
+        file: Option<PathBuf>,
+    },
+    /// Remove the sourcing added by `eashy install` from the RC files
+    Uninstall {
+        /// Print the changes to the RC files without writing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Run the examples declared on commands in a real shell
+    Test {
+        /// Shell running the examples, can be repeated [default: bash and zsh, if installed]
+        #[arg(long, value_name = "SHELL")]
+        shell: Vec<String>,
+    },
+    /// Regenerate the shell script whenever the KDL files change
+    Watch,
+    /// Print the functions and aliases of a bash or zsh file as KDL commands
+    Import {
+        /// Shell file to import, like ~/.bash_aliases
+        file: PathBuf,
+    },
+    /// Print the reference documentation of every command
+    Docs {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = DocsFormat::Markdown)]
+        format: DocsFormat,
+        /// Write one file per top-level command in this directory, instead of printing them
+        #[arg(long, value_name = "DIR")]
+        dir: Option<PathBuf>,
+    },
+    /// Print the resolved command tree, for editors and other tools
+    Dump {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = DumpFormat::Json)]
+        format: DumpFormat,
+    },
+}
+
+/// Formats of `eashy docs`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DocsFormat {
+    /// Markdown page, for wikis and READMEs
+    Markdown,
+    /// Man page, in roff
+    Man,
+    /// Standalone HTML page
+    Html,
+}
+
+impl DocsFormat {
+    /// Get the extension of the files written with `--dir`
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            DocsFormat::Markdown => "md",
+            DocsFormat::Man => "1",
+            DocsFormat::Html => "html",
+        }
+    }
+}
+
+/// Formats of `eashy dump`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DumpFormat {
+    /// Commands with their paths, function names, arguments and locations
+    Json,
 }
 
 impl Cli {
@@ -64,20 +175,63 @@ impl Cli {
         Parser::parse()
     }
 
-    /// Get the resolved input file path
+    /// Get the resolved input path, which can be a file or a directory
     pub fn get_input_file(&self) -> Result<PathBuf, Error> {
         let path = self.file.clone().unwrap_or_else(|| {
-            // This should not happen since we have a default_value, but just in case
-            dirs::home_dir()
+            let folder = dirs::home_dir()
                 .unwrap_or_else(|| PathBuf::from("."))
-                .join(FOLDER_DIR)
-                .join("default.kdl")
+                .join(FOLDER_DIR);
+            let commands_dir = folder.join(COMMANDS_DIR);
+            if commands_dir.is_dir() {
+                commands_dir
+            } else {
+                folder.join("default.kdl")
+            }
         });
 
         // Expand ~ if present
         Ok(expand_tilde(path))
     }
 
+    /// Get every definition file to parse, sorted by file name when the input is a directory
+    pub fn get_input_files(&self) -> Result<Vec<PathBuf>, Error> {
+        let path = self.get_input_file()?;
+        if !path.is_dir() {
+            return Ok(vec![path]);
+        }
+
+        let mut files = std::fs::read_dir(&path)
+            .map_err(|e| {
+                Error::msg(format!(
+                    "Failed to read directory '{}': {}",
+                    path.display(),
+                    e
+                ))
+            })?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|file| file.is_file() && Format::from_path(file).is_some())
+            .collect::<Vec<PathBuf>>();
+        files.sort();
+
+        if files.is_empty() {
+            return Err(Error::msg(format!(
+                "No command files found in directory '{}'",
+                path.display()
+            )));
+        }
+        Ok(files)
+    }
+
+    /// Get the KDL file written by `eashy init`
+    pub fn get_init_file(&self) -> Result<PathBuf, Error> {
+        let path = self.get_input_file()?;
+        if path.is_dir() {
+            Ok(path.join("default.kdl"))
+        } else {
+            Ok(path)
+        }
+    }
+
     /// Check if output should go to stdout
     pub fn is_stdout_output(&self) -> bool {
         self.output.as_deref() == Some("-")
@@ -104,6 +258,28 @@ impl Cli {
         }
     }
 
+    /// Get the arguments regenerating the same script from the prompt hook
+    pub fn get_reload_args(&self, output_file: &Path) -> Result<Vec<String>, Error> {
+        // The hook runs from any directory, so paths must be absolute
+        let mut args = vec![
+            "--quiet".to_string(),
+            "--file".to_string(),
+            std::path::absolute(self.get_input_file()?)?
+                .display()
+                .to_string(),
+            "--output".to_string(),
+            std::path::absolute(output_file)?.display().to_string(),
+            "--reload".to_string(),
+        ];
+        if self.project {
+            args.push("--project".to_string());
+        }
+        if self.keep_going {
+            args.push("--keep-going".to_string());
+        }
+        Ok(args)
+    }
+
     /// Print success messages and sourcing instructions
     pub fn print_success_message(&self, output_file: &Path) {
         if self.quiet {
@@ -114,30 +290,12 @@ impl Cli {
             "✅ Shell script generated successfully at: {}",
             output_file.display()
         );
-        // Check if already sourced in RC file
-        let shell = Cli::get_shell();
-        let rc_file = if shell.contains("zsh") {
-            dirs::home_dir().map(|h| h.join(".zshrc"))
-        } else if shell.contains("bash") {
-            dirs::home_dir().map(|h| h.join(".bashrc"))
-        } else if shell.contains("fish") {
-            dirs::home_dir().map(|h| h.join(".config/fish/config.fish"))
-        } else {
-            None
-        };
-
-        let already_sourced = if let Some(rc_path) = rc_file {
-            if rc_path.exists() {
-                std::fs::read_to_string(&rc_path)
-                    .ok()
-                    .map(|content| content.contains(&format!("source {}", output_file.display())))
-                    .unwrap_or(false)
-            } else {
-                false
-            }
-        } else {
-            false
-        };
+        // Check if already sourced in an RC file
+        let already_sourced = crate::install::get_rc_files()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|rc_file| std::fs::read_to_string(rc_file).ok())
+            .any(|content| crate::install::is_sourced(&content, output_file));
 
         if already_sourced {
             return;
@@ -146,21 +304,8 @@ impl Cli {
         println!("📋 To use the generated commands, you need to source this file:");
         println!("   source {}", output_file.display());
         println!();
-        println!("🔧 To make it permanent, add this line to your shell's RC file, or run:");
-
-        // Detect shell and provide appropriate RC file suggestion
-        let shell = Cli::get_shell();
-        let rc_file = if shell.contains("zsh") {
-            "~/.zshrc"
-        } else if shell.contains("bash") {
-            "~/.bashrc"
-        } else if shell.contains("fish") {
-            "~/.config/fish/config.fish"
-        } else {
-            "your shell's configuration file"
-        };
-
-        println!("   echo 'source {}' >> {}", output_file.display(), rc_file);
+        println!("🔧 To make it permanent, source it from your shell's RC file by running:");
+        println!("   eashy install");
         println!();
     }
     pub fn get_shell() -> String {
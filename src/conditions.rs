@@ -0,0 +1,225 @@
+//! Conditions on the machine a command is available on
+//!
+//! A `when` child keeps a command only on the machines matching all its
+//! conditions. They are checked when generating the script, dropping the
+//! command otherwise, or when running the command with `runtime=#true`:
+//!
+//! ```kdl
+//! kube {
+//!     when os="linux"
+//!     when has="kubectl" runtime=#true
+//!     pods { kubectl get pods; }
+//! }
+//! ```
+
+use anyhow::Error;
+use kdl::{KdlNode, KdlValue};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Condition on the machine running a command
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Condition {
+    /// Operating system, as named by Rust: `linux`, `macos`, `windows`...
+    Os(String),
+    /// Program found in the `PATH`
+    Has(String),
+    /// Host name, as a glob pattern with `*` and `?`
+    Host(String),
+}
+
+/// Supported operating systems, with the pattern of their `uname -s` name
+const SYSTEMS: [(&str, &str); 6] = [
+    ("linux", "Linux"),
+    ("macos", "Darwin"),
+    ("windows", "MINGW*|MSYS*|CYGWIN*"),
+    ("freebsd", "FreeBSD"),
+    ("openbsd", "OpenBSD"),
+    ("netbsd", "NetBSD"),
+];
+
+impl Condition {
+    /// Create a condition from its `when` property
+    pub(crate) fn new(key: &str, value: &str) -> Result<Self, Error> {
+        match key {
+            "os" if SYSTEMS.iter().any(|(os, _)| *os == value) => Ok(Condition::Os(value.into())),
+            "os" => Err(Error::msg(format!(
+                "unknown os '{}', expected one of: {}",
+                value,
+                SYSTEMS.map(|(os, _)| os).join(", ")
+            ))),
+            // Printed in double quotes by the runtime check, so no shell syntax
+            "has"
+                if !value.is_empty()
+                    && value
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "-_.+/@".contains(c)) =>
+            {
+                Ok(Condition::Has(value.into()))
+            }
+            "has" => Err(Error::msg(format!("invalid program name '{}'", value))),
+            "host"
+                if !value.is_empty()
+                    && value
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "-_.*?".contains(c)) =>
+            {
+                Ok(Condition::Host(value.into()))
+            }
+            "host" => Err(Error::msg(format!("invalid host pattern '{}'", value))),
+            _ => Err(Error::msg(format!(
+                "unknown condition '{}', expected os, has or host",
+                key
+            ))),
+        }
+    }
+
+    /// Check the condition can be written in the generated script, like the
+    /// ones of `when` nodes, for conditions from other formats or the builder
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        match self {
+            Condition::Os(os) => Condition::new("os", os),
+            Condition::Has(program) => Condition::new("has", program),
+            Condition::Host(pattern) => Condition::new("host", pattern),
+        }
+        .map(|_| ())
+    }
+
+    /// Check the condition on the current machine
+    pub fn is_met(&self) -> bool {
+        match self {
+            Condition::Os(os) => os == std::env::consts::OS,
+            Condition::Has(program) => find_program(program),
+            Condition::Host(pattern) => get_hostname().is_some_and(|host| matches(pattern, &host)),
+        }
+    }
+
+    /// Get the pattern matching the output of `uname -s` on an operating
+    /// system, checked by [`Condition::validate`]
+    pub(crate) fn get_uname_pattern(os: &str) -> &'static str {
+        SYSTEMS
+            .iter()
+            .find(|(name, _)| *name == os)
+            .map_or("*", |(_, pattern)| pattern)
+    }
+}
+
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Condition::Os(os) => f.write_str(os),
+            Condition::Has(program) => f.write_str(program),
+            Condition::Host(pattern) => write!(f, "a host matching '{}'", pattern),
+        }
+    }
+}
+
+/// Conditions of a `when` node, checked at runtime or when generating the script
+pub(crate) struct When {
+    pub conditions: Vec<Condition>,
+    pub runtime: bool,
+}
+
+impl When {
+    /// Parse a `when key="value"...` node, `None` if the node is not one
+    pub fn parse(node: &KdlNode) -> Option<Result<Self, Error>> {
+        if node.name().value() != "when"
+            || node.children().is_some()
+            || node.entries().is_empty()
+            || node.entries().iter().any(|entry| entry.name().is_none())
+        {
+            return None;
+        }
+
+        let mut when = When {
+            conditions: Vec::new(),
+            runtime: false,
+        };
+        for entry in node.entries() {
+            let key = entry.name().map_or("", |name| name.value());
+            match (key, entry.value()) {
+                ("runtime", KdlValue::Bool(runtime)) => when.runtime = *runtime,
+                ("runtime", _) => {
+                    return Some(Err(Error::msg("'runtime' of `when` is #true or #false")));
+                }
+                (_, KdlValue::String(value)) => match Condition::new(key, value) {
+                    Ok(condition) => when.conditions.push(condition),
+                    Err(e) => return Some(Err(e)),
+                },
+                _ => {
+                    return Some(Err(Error::msg(format!(
+                        "condition '{}' takes a string",
+                        key
+                    ))));
+                }
+            }
+        }
+        Some(Ok(when))
+    }
+}
+
+/// Check the conditions of a command node checked when generating the script,
+/// invalid ones being reported when parsing the command
+pub(crate) fn is_included(node: &KdlNode) -> bool {
+    node.children()
+        .map(|children| children.nodes())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(When::parse)
+        .filter_map(Result::ok)
+        .filter(|when| !when.runtime)
+        .all(|when| when.conditions.iter().all(Condition::is_met))
+}
+
+/// Check if a program is a file of the `PATH`, or a path to a file
+fn find_program(program: &str) -> bool {
+    if program.contains('/') {
+        return Path::new(program).is_file();
+    }
+    std::env::var_os("PATH").is_some_and(|paths| {
+        std::env::split_paths(&paths).any(|dir| {
+            dir.join(program).is_file()
+                || (cfg!(windows) && dir.join(format!("{}.exe", program)).is_file())
+        })
+    })
+}
+
+/// Get the name of the current machine, as given by `uname -n`
+fn get_hostname() -> Option<String> {
+    ["uname -n", "hostname"].iter().find_map(|command| {
+        let mut words = command.split(' ');
+        let output = std::process::Command::new(words.next()?)
+            .args(words)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+        let host = String::from_utf8(output.stdout).ok()?.trim().to_string();
+        (!host.is_empty()).then_some(host)
+    })
+}
+
+/// Match a text against a glob pattern, with `*` and `?` wildcards
+fn matches(pattern: &str, text: &str) -> bool {
+    let (pattern, text): (Vec<char>, Vec<char>) =
+        (pattern.chars().collect(), text.chars().collect());
+    // Position of the last `*` in the pattern, and of the text it matches up to
+    let mut star = None;
+    let (mut p, mut t) = (0, 0);
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
@@ -0,0 +1,373 @@
+//! Reference documentation of the commands, in Markdown, man page or HTML
+//!
+//! Every command and subcommand gets a section with its description, usage,
+//! subcommands, arguments, options with their default value, and examples.
+
+use kdl::KdlValue;
+
+use crate::parser::{self, ArgPrefix, Argument, Children, Command};
+
+/// Generate a Markdown page, with a heading per command
+pub fn generate_markdown(commands: &[Command]) -> String {
+    let mut output = String::new();
+    for command in flatten(commands) {
+        let level = if command.path.len() == 1 { "#" } else { "##" };
+        output.push_str(&format!(
+            "{} {}\n\n",
+            level,
+            command.get_command_path_string()
+        ));
+        if let Some(description) = &command.description {
+            output.push_str(&format!("{}\n\n", description));
+        }
+        output.push_str(&format!("```sh\n{}\n```\n\n", get_usage(command)));
+
+        if let Children::Subcmds(subcommands) = &command.children {
+            output.push_str("**Commands:**\n\n| Command | Description |\n| --- | --- |\n");
+            for subcommand in subcommands {
+                output.push_str(&format!(
+                    "| [`{}`](#{}) | {} |\n",
+                    subcommand.name,
+                    get_anchor(subcommand),
+                    escape_markdown_cell(subcommand.description.as_deref().unwrap_or_default())
+                ));
+            }
+            output.push('\n');
+        }
+
+        let positional_args = command.get_positional_arguments();
+        if !positional_args.is_empty() {
+            output.push_str("**Arguments:**\n\n| Argument | Description |\n| --- | --- |\n");
+            for arg in positional_args {
+                output.push_str(&format!(
+                    "| `{}` | {} |\n",
+                    get_positional_usage(arg),
+                    escape_markdown_cell(&arg.help)
+                ));
+            }
+            output.push('\n');
+        }
+
+        let optional_args = command.get_optional_arguments();
+        if !optional_args.is_empty() {
+            output.push_str(&format!(
+                "**{}:**\n\n| Option | Default | Description |\n| --- | --- | --- |\n",
+                get_options_title(command)
+            ));
+            for arg in optional_args {
+                output.push_str(&format!(
+                    "| `{}` | {} | {} |\n",
+                    get_option_usage(arg),
+                    get_default(arg).map_or(String::new(), |value| format!("`{}`", value)),
+                    escape_markdown_cell(&arg.help)
+                ));
+            }
+            output.push('\n');
+        }
+
+        if !command.examples.is_empty() {
+            output.push_str("**Examples:**\n\n```sh\n");
+            for line in get_example_lines(command) {
+                output.push_str(&format!("{}\n", line));
+            }
+            output.push_str("```\n\n");
+        }
+    }
+    output
+}
+
+/// Generate a man page in roff, with a section per command
+pub fn generate_man(commands: &[Command]) -> String {
+    let title = match commands {
+        [command] => command.name.to_uppercase(),
+        _ => "EASHY".to_string(),
+    };
+    let mut output = format!(
+        ".TH \"{}\" 1 \"\" \"eashy\" \"User Commands\"\n",
+        escape_roff(&title)
+    );
+
+    output.push_str(".SH NAME\n");
+    let names: Vec<&str> = commands.iter().map(|c| c.name.as_str()).collect();
+    let description = match commands {
+        [command] => command.description.as_deref(),
+        _ => None,
+    };
+    output.push_str(&format!(
+        "{} \\- {}\n",
+        escape_roff(&names.join(", ")),
+        escape_roff(description.unwrap_or("shell functions generated by eashy"))
+    ));
+
+    output.push_str(".SH SYNOPSIS\n.nf\n");
+    for command in commands {
+        output.push_str(&format!("{}\n", escape_roff(&get_usage(command))));
+    }
+    output.push_str(".fi\n");
+
+    output.push_str(".SH COMMANDS\n");
+    for command in flatten(commands) {
+        output.push_str(&format!(
+            ".SS \"{}\"\n",
+            escape_roff(&command.get_command_path_string())
+        ));
+        if let Some(description) = &command.description {
+            output.push_str(&format!("{}\n", escape_roff(description)));
+        }
+        output.push_str(&format!(
+            ".PP\n.nf\n{}\n.fi\n",
+            escape_roff(&get_usage(command))
+        ));
+
+        let mut items = Vec::new();
+        if let Children::Subcmds(subcommands) = &command.children {
+            for subcommand in subcommands {
+                items.push((
+                    subcommand.name.clone(),
+                    subcommand.description.clone().unwrap_or_default(),
+                ));
+            }
+        }
+        for arg in command.get_positional_arguments() {
+            items.push((get_positional_usage(arg), arg.help.clone()));
+        }
+        for arg in command.get_optional_arguments() {
+            let help = match get_default(arg) {
+                Some(value) => format!("{} (default: {})", arg.help, value),
+                None => arg.help.clone(),
+            };
+            items.push((get_option_usage(arg), help));
+        }
+        for (name, help) in items {
+            output.push_str(&format!(
+                ".TP\n.B \"{}\"\n{}\n",
+                escape_roff(&name),
+                escape_roff(&help)
+            ));
+        }
+
+        if !command.examples.is_empty() {
+            output.push_str(".PP\nExamples:\n.PP\n.nf\n");
+            for line in get_example_lines(command) {
+                output.push_str(&format!("{}\n", escape_roff(&line)));
+            }
+            output.push_str(".fi\n");
+        }
+    }
+    output
+}
+
+/// Generate a standalone HTML page, with a section per command
+pub fn generate_html(commands: &[Command]) -> String {
+    let title = match commands {
+        [command] => command.name.clone(),
+        _ => "Commands".to_string(),
+    };
+    let mut output = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{}</title>
+<style>
+body {{ font-family: sans-serif; max-width: 50em; margin: auto; padding: 1em; }}
+pre {{ background: #f4f4f4; padding: 0.5em; }}
+table {{ border-collapse: collapse; }}
+td, th {{ border: 1px solid #ddd; padding: 0.3em 0.6em; text-align: left; }}
+</style>
+</head>
+<body>
+"#,
+        escape_html(&title)
+    );
+
+    for command in flatten(commands) {
+        let level = if command.path.len() == 1 { 1 } else { 2 };
+        output.push_str(&format!(
+            "<section id=\"{}\">\n<h{level}>{}</h{level}>\n",
+            get_anchor(command),
+            escape_html(&command.get_command_path_string()),
+        ));
+        if let Some(description) = &command.description {
+            output.push_str(&format!("<p>{}</p>\n", escape_html(description)));
+        }
+        output.push_str(&format!(
+            "<pre><code>{}</code></pre>\n",
+            escape_html(&get_usage(command))
+        ));
+
+        if let Children::Subcmds(subcommands) = &command.children {
+            output.push_str(
+                "<h3>Commands</h3>\n<table>\n<tr><th>Command</th><th>Description</th></tr>\n",
+            );
+            for subcommand in subcommands {
+                output.push_str(&format!(
+                    "<tr><td><a href=\"#{}\"><code>{}</code></a></td><td>{}</td></tr>\n",
+                    get_anchor(subcommand),
+                    escape_html(&subcommand.name),
+                    escape_html(subcommand.description.as_deref().unwrap_or_default())
+                ));
+            }
+            output.push_str("</table>\n");
+        }
+
+        let positional_args = command.get_positional_arguments();
+        if !positional_args.is_empty() {
+            output.push_str(
+                "<h3>Arguments</h3>\n<table>\n<tr><th>Argument</th><th>Description</th></tr>\n",
+            );
+            for arg in positional_args {
+                output.push_str(&format!(
+                    "<tr><td><code>{}</code></td><td>{}</td></tr>\n",
+                    escape_html(&get_positional_usage(arg)),
+                    escape_html(&arg.help)
+                ));
+            }
+            output.push_str("</table>\n");
+        }
+
+        let optional_args = command.get_optional_arguments();
+        if !optional_args.is_empty() {
+            output.push_str(&format!(
+                "<h3>{}</h3>\n<table>\n<tr><th>Option</th><th>Default</th><th>Description</th></tr>\n",
+                get_options_title(command)
+            ));
+            for arg in optional_args {
+                output.push_str(&format!(
+                    "<tr><td><code>{}</code></td><td>{}</td><td>{}</td></tr>\n",
+                    escape_html(&get_option_usage(arg)),
+                    get_default(arg).map_or(String::new(), |value| format!(
+                        "<code>{}</code>",
+                        escape_html(&value)
+                    )),
+                    escape_html(&arg.help)
+                ));
+            }
+            output.push_str("</table>\n");
+        }
+
+        if !command.examples.is_empty() {
+            output.push_str("<h3>Examples</h3>\n<pre><code>");
+            for line in get_example_lines(command) {
+                output.push_str(&format!("{}\n", escape_html(&line)));
+            }
+            output.push_str("</code></pre>\n");
+        }
+        output.push_str("</section>\n");
+    }
+
+    output.push_str("</body>\n</html>\n");
+    output
+}
+
+/// Get every command and subcommand, depth first
+fn flatten(commands: &[Command]) -> Vec<&Command> {
+    let mut flattened = Vec::new();
+    for command in commands {
+        flattened.push(command);
+        if let Children::Subcmds(subcommands) = &command.children {
+            flattened.extend(flatten(subcommands));
+        }
+    }
+    flattened
+}
+
+fn get_usage(command: &Command) -> String {
+    parser::strip_colors(&command.get_usage_string())
+}
+
+/// Get the anchor of a command section, like `venv-init`
+fn get_anchor(command: &Command) -> String {
+    command.path.join("-").to_lowercase()
+}
+
+/// Get the title of the options, global to the subcommands of a command
+fn get_options_title(command: &Command) -> &'static str {
+    match command.children {
+        Children::Subcmds(_) => "Global options",
+        Children::Body(_) => "Options",
+    }
+}
+
+fn get_positional_usage(arg: &Argument) -> String {
+    match arg.prefix {
+        ArgPrefix::None => format!("<{}>", arg.name),
+        ArgPrefix::ZeroMore => format!("[<{}> ...]", arg.name),
+        ArgPrefix::OneMore => format!("<{}> [<{}> ...]", arg.name, arg.name),
+        ArgPrefix::ZeroOne => format!("[<{}>]", arg.name),
+    }
+}
+
+fn get_option_usage(arg: &Argument) -> String {
+    let flag = if arg.name.len() == 1 {
+        format!("-{}", arg.name)
+    } else {
+        format!("--{}", arg.name)
+    };
+    if matches!(arg.option, Some(KdlValue::Bool(_))) {
+        flag
+    } else {
+        format!("{} <{}>", flag, arg.name)
+    }
+}
+
+/// Get the default value of an option, if it has a non-empty one
+fn get_default(arg: &Argument) -> Option<String> {
+    match arg.option.as_ref()? {
+        KdlValue::Null => None,
+        KdlValue::String(value) if value.is_empty() => None,
+        KdlValue::String(value) => Some(value.clone()),
+        value => Some(value.to_string().trim_start_matches('#').to_string()),
+    }
+}
+
+/// Get the example invocations as a shell session, with the expected outputs as comments
+fn get_example_lines(command: &Command) -> Vec<String> {
+    let mut lines = Vec::new();
+    for example in &command.examples {
+        let invocation = if example.args.is_empty() {
+            command.get_command_path_string()
+        } else {
+            format!("{} {}", command.get_command_path_string(), example.args)
+        };
+        lines.push(format!("$ {}", invocation));
+        if let Some(stdout) = &example.stdout {
+            lines.push(format!("# Prints: {}", stdout));
+        }
+        if let Some(stderr) = &example.stderr {
+            lines.push(format!("# Prints to stderr: {}", stderr));
+        }
+        if example.status != 0 {
+            lines.push(format!("# Exits with status {}", example.status));
+        }
+    }
+    lines
+}
+
+fn escape_markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Escape text for roff, including the control characters starting a line
+fn escape_roff(s: &str) -> String {
+    s.replace('\\', "\\e")
+        .replace('-', "\\-")
+        .replace('"', "\\(dq")
+        .lines()
+        .map(|line| {
+            if line.starts_with('.') || line.starts_with('\'') {
+                format!("\\&{}", line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -0,0 +1,258 @@
+//! Command definitions in JSON, YAML and TOML, as an alternative to KDL
+//!
+//! Every format holds a `commands` list, with the same semantics as KDL:
+//!
+//! ```json
+//! {
+//!   "commands": [
+//!     {
+//!       "name": "greet",
+//!       "description": "Greet someone",
+//!       "arguments": [
+//!         { "name": "name", "help": "Who to greet" },
+//!         { "name": "loud", "default": false }
+//!       ],
+//!       "body": ["echo \"Hello $name\""]
+//!     }
+//!   ]
+//! }
+//! ```
+
+use kdl::KdlValue;
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::conditions::Condition;
+use crate::parser::{ArgPrefix, Argument, Children, CmdPrefix, Command, Example, Hooks};
+
+/// Top-level of a definition file
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Document {
+    pub commands: Vec<Command>,
+}
+
+/// Serialized form of a [`Command`], with subcommands and body lines as separate fields
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CommandDef {
+    name: String,
+    #[serde(default, skip_serializing_if = "is_default")]
+    prefix: CmdPrefix,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    arguments: Vec<Argument>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    subcommands: Vec<Command>,
+    /// Body lines, run when no subcommand is given if there are subcommands
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    body: Vec<String>,
+    /// Conditions checked when running the command
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    requires: Vec<Condition>,
+    #[serde(default, skip_serializing_if = "Hooks::is_empty")]
+    hooks: Hooks,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cwd: Option<String>,
+    /// Environment variables, in the order of the document
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_env",
+        deserialize_with = "deserialize_env"
+    )]
+    env: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    affects_caller: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    raw_args: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    examples: Vec<Example>,
+}
+
+impl From<CommandDef> for Command {
+    fn from(def: CommandDef) -> Self {
+        let (children, default_body) = if def.subcommands.is_empty() {
+            (Children::Body(def.body), Vec::new())
+        } else {
+            (Children::Subcmds(def.subcommands), def.body)
+        };
+
+        let mut command = Command {
+            path: Vec::new(),
+            name: def.name,
+            prefix: def.prefix,
+            description: def.description,
+            arguments: def.arguments,
+            children,
+            default_body,
+            requires: def.requires,
+            hooks: def.hooks,
+            cwd: def.cwd,
+            env: def.env,
+            affects_caller: def.affects_caller,
+            raw_args: def.raw_args,
+            examples: def.examples,
+            location: None,
+        };
+        // Paths are updated again by the parent command, if any
+        command.set_parent_path(&[]);
+        command
+    }
+}
+
+impl From<Command> for CommandDef {
+    fn from(command: Command) -> Self {
+        let (subcommands, body) = match command.children {
+            Children::Subcmds(subcommands) => (subcommands, command.default_body),
+            Children::Body(lines) => (Vec::new(), lines),
+        };
+        CommandDef {
+            name: command.name,
+            prefix: command.prefix,
+            description: command.description,
+            arguments: command.arguments,
+            subcommands,
+            body,
+            requires: command.requires,
+            hooks: command.hooks,
+            cwd: command.cwd,
+            env: command.env,
+            affects_caller: command.affects_caller,
+            raw_args: command.raw_args,
+            examples: command.examples,
+        }
+    }
+}
+
+/// Serialized form of an [`Argument`], an option being an argument with a default value
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ArgumentDef {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    prefix: ArgPrefix,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_some",
+        skip_serializing_if = "Option::is_none"
+    )]
+    default: Option<DefaultValue>,
+}
+
+impl From<ArgumentDef> for Argument {
+    fn from(def: ArgumentDef) -> Self {
+        let option: Option<KdlValue> = def.default.map(Into::into);
+        // Options take zero or one value, unless repeatable, as in KDL
+        let prefix = match def.prefix {
+            ArgPrefix::ZeroMore => ArgPrefix::ZeroMore,
+            _ if option.is_some() => ArgPrefix::ZeroOne,
+            prefix => prefix,
+        };
+        Argument {
+            help: def.help.unwrap_or_else(|| def.name.to_uppercase()),
+            name: def.name,
+            prefix,
+            option,
+        }
+    }
+}
+
+impl From<Argument> for ArgumentDef {
+    fn from(arg: Argument) -> Self {
+        ArgumentDef {
+            name: arg.name,
+            help: Some(arg.help),
+            prefix: arg.prefix,
+            default: arg.option.map(Into::into),
+        }
+    }
+}
+
+/// Default value of an option, as a native value of the format
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum DefaultValue {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl From<DefaultValue> for KdlValue {
+    fn from(value: DefaultValue) -> Self {
+        match value {
+            DefaultValue::Null => KdlValue::Null,
+            DefaultValue::Bool(b) => KdlValue::Bool(b),
+            DefaultValue::Integer(i) => KdlValue::Integer(i.into()),
+            DefaultValue::Float(f) => KdlValue::Float(f),
+            DefaultValue::String(s) => KdlValue::String(s),
+        }
+    }
+}
+
+impl From<KdlValue> for DefaultValue {
+    fn from(value: KdlValue) -> Self {
+        match value {
+            KdlValue::Bool(b) => DefaultValue::Bool(b),
+            KdlValue::Float(f) => DefaultValue::Float(f),
+            KdlValue::String(s) => DefaultValue::String(s),
+            KdlValue::Integer(i) => match i64::try_from(i) {
+                Ok(i) => DefaultValue::Integer(i),
+                Err(_) => DefaultValue::String(i.to_string()),
+            },
+            KdlValue::Null => DefaultValue::Null,
+        }
+    }
+}
+
+/// Deserialize a present value as `Some`, so that an explicit `null` default
+/// makes an option, while a missing one makes a positional argument
+fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Serialize environment variables as a map, in their order
+pub(crate) fn serialize_env<S: Serializer>(
+    env: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(env.iter().map(|(name, value)| (name, value)))
+}
+
+/// Deserialize environment variables from a map, keeping their order
+fn deserialize_env<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<(String, String)>, D::Error> {
+    struct EnvVisitor;
+
+    impl<'de> Visitor<'de> for EnvVisitor {
+        type Value = Vec<(String, String)>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a map of environment variables")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut env = Vec::new();
+            while let Some(variable) = map.next_entry()? {
+                env.push(variable);
+            }
+            Ok(env)
+        }
+    }
+
+    deserializer.deserialize_map(EnvVisitor)
+}
+
+pub(crate) fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
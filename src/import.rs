@@ -0,0 +1,422 @@
+use anyhow::Error;
+use kdl::{KdlIdentifier, KdlValue};
+use std::fs;
+use std::path::Path;
+
+use eashy::Children;
+
+/// A command translated from a shell function or alias
+struct Imported {
+    name: String,
+    description: Option<String>,
+    arguments: Vec<String>,
+    raw_args: bool,
+    lines: Vec<String>,
+    /// Constructs that could not be translated, reported in the output as comments
+    warnings: Vec<String>,
+}
+
+/// Translate the functions and aliases of a bash or zsh file into KDL commands,
+/// warning about what could not be translated
+pub fn import(file: &Path, quiet: bool) -> Result<String, Error> {
+    let content = fs::read_to_string(file).map_err(|e| {
+        Error::msg(format!(
+            "Failed to read shell file '{}': {}",
+            file.display(),
+            e
+        ))
+    })?;
+
+    let (mut commands, skipped) = parse_shell(&content);
+    // The shell keeps the last definition of a name
+    let mut index = 0;
+    while index < commands.len() {
+        if commands[index + 1..]
+            .iter()
+            .any(|c| c.name == commands[index].name)
+        {
+            let command = commands.remove(index);
+            if !quiet {
+                eprintln!("⚠️  {}: defined again later, skipped", command.name);
+            }
+        } else {
+            index += 1;
+        }
+    }
+
+    let mut output = format!("// Imported from {} by `eashy import`\n", file.display());
+    let mut imported = 0;
+    for command in &commands {
+        let kdl = to_kdl(command);
+        if let Err(e) = eashy::parse_str(&kdl) {
+            if !quiet {
+                eprintln!("⚠️  {}: not imported, {}", command.name, e);
+            }
+            continue;
+        }
+        output.push('\n');
+        output.push_str(&kdl);
+        imported += 1;
+        if !quiet {
+            for warning in &command.warnings {
+                eprintln!("⚠️  {}: {}", command.name, warning);
+            }
+            for line in command.lines.iter().filter(|l| to_kdl_line(l).is_none()) {
+                eprintln!("⚠️  {}: could not translate '{}'", command.name, line);
+            }
+        }
+    }
+    if !quiet {
+        for (line_number, line) in &skipped {
+            eprintln!(
+                "⚠️  line {}: skipped '{}', only functions and aliases are imported",
+                line_number, line
+            );
+        }
+        if imported == 0 {
+            eprintln!(
+                "⚠️  No functions or aliases imported from {}",
+                file.display()
+            );
+        }
+    }
+
+    Ok(output)
+}
+
+/// Find the functions and aliases of a shell file, with the top-level lines skipped
+fn parse_shell(content: &str) -> (Vec<Imported>, Vec<(usize, String)>) {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut commands = Vec::new();
+    let mut skipped = Vec::new();
+    let mut comments: Vec<&str> = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index].trim();
+        index += 1;
+
+        if let Some(comment) = line.strip_prefix('#') {
+            if !line.starts_with("#!") {
+                comments.push(comment.trim());
+            }
+            continue;
+        }
+        // The comment right above a function or alias describes it
+        let description = (!comments.is_empty()).then(|| comments.join(" "));
+        comments.clear();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(rest) = line.strip_prefix("alias ") {
+            match parse_alias(rest) {
+                Some(mut command) => {
+                    command.description = description;
+                    commands.push(command);
+                }
+                None => skipped.push((index, line.to_string())),
+            }
+        } else if let Some((name, rest)) = parse_function_header(line) {
+            let mut rest = rest.map(str::to_string);
+            if rest.is_none() && lines.get(index).is_some_and(|l| l.trim() == "{") {
+                rest = Some(String::new());
+                index += 1;
+            }
+            let Some(rest) = rest else {
+                skipped.push((index, line.to_string()));
+                continue;
+            };
+
+            let (body, consumed) = collect_body(&rest, &lines[index..]);
+            index += consumed;
+            let mut command = translate_function(name, body);
+            command.description = description;
+            commands.push(command);
+        } else {
+            skipped.push((index, line.to_string()));
+        }
+    }
+
+    (commands, skipped)
+}
+
+/// Parse `name() {`, `name () {` or `function name {`, returning the name and
+/// what follows the opening brace, if on the same line
+fn parse_function_header(line: &str) -> Option<(&str, Option<&str>)> {
+    let (name, rest) = match line.strip_prefix("function ") {
+        Some(rest) => {
+            let rest = rest.trim_start();
+            let end = rest.find(|c: char| c.is_whitespace() || c == '(' || c == '{')?;
+            let (name, rest) = rest.split_at(end);
+            (name, rest.trim_start().strip_prefix("()").unwrap_or(rest))
+        }
+        None => {
+            let end = line.find('(')?;
+            let name = line[..end].trim_end();
+            (name, line[end..].strip_prefix("()")?)
+        }
+    };
+    if name.is_empty() || name.contains(|c: char| c.is_whitespace() || "=$\"'`".contains(c)) {
+        return None;
+    }
+
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        Some((name, None))
+    } else {
+        Some((name, Some(rest.strip_prefix('{')?)))
+    }
+}
+
+/// Collect the body lines until the brace closing the function, returning
+/// them with the number of lines consumed after the header
+fn collect_body(first: &str, lines: &[&str]) -> (Vec<String>, usize) {
+    let mut body = Vec::new();
+    let mut scanner = Scanner::default();
+    let mut depth = 1;
+
+    let candidates = std::iter::once(first).chain(lines.iter().copied());
+    for (consumed, line) in candidates.enumerate() {
+        if let Some(end) = scanner.find_closing_brace(line, &mut depth) {
+            let last = line[..end].trim_end().trim_end_matches(';').trim_end();
+            if !last.trim().is_empty() {
+                body.push(last.to_string());
+            }
+            return (body, consumed);
+        }
+        if consumed > 0 || !line.trim().is_empty() {
+            body.push(line.trim_end().to_string());
+        }
+    }
+    (body, lines.len())
+}
+
+/// Quote state of shell text, kept from one line to the next
+#[derive(Default)]
+struct Scanner {
+    single: bool,
+    double: bool,
+}
+
+impl Scanner {
+    /// Update the brace depth with the line, returning where it drops to zero
+    fn find_closing_brace(&mut self, line: &str, depth: &mut usize) -> Option<usize> {
+        let mut chars = line.char_indices().peekable();
+        let mut previous = ' ';
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '\\' if !self.single => {
+                    chars.next();
+                }
+                '\'' if !self.double => self.single = !self.single,
+                '"' if !self.single => self.double = !self.double,
+                '#' if !self.single && !self.double && previous.is_whitespace() => break,
+                '{' if !self.single && !self.double => *depth += 1,
+                '}' if !self.single && !self.double => {
+                    *depth -= 1;
+                    if *depth == 0 {
+                        return Some(index);
+                    }
+                }
+                _ => {}
+            }
+            previous = c;
+        }
+        None
+    }
+}
+
+/// Translate a function body, with its positional parameters as arguments
+fn translate_function(name: &str, body: Vec<String>) -> Imported {
+    let mut warnings = Vec::new();
+    // Indentation is not significant, and the generator indents body lines anyway
+    let lines: Vec<String> = body
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect();
+
+    if lines
+        .iter()
+        .any(|line| line.contains("<<") && !line.contains("<<<"))
+    {
+        warnings
+            .push("uses a heredoc, whose lines get indented in the generated function".to_string());
+    }
+
+    Imported {
+        name: name.to_string(),
+        description: None,
+        arguments: Vec::new(),
+        // With its arguments given as they are, the body reads them like the function did
+        raw_args: lines.iter().any(|line| uses_parameters(line)),
+        lines,
+        warnings,
+    }
+}
+
+/// Check if a line reads the arguments of the function: `$1` to `$9`,
+/// `${1}`, `$@`, `$*`, `$#` outside single quotes, or `shift`
+fn uses_parameters(line: &str) -> bool {
+    if line.split_whitespace().next() == Some("shift") {
+        return true;
+    }
+    let (mut single, mut double) = (false, false);
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if !single => {
+                chars.next();
+            }
+            '\'' if !double => single = !single,
+            '"' if !single => double = !double,
+            '$' if !single => {
+                let mut lookahead = chars.clone();
+                let braced = lookahead.next_if_eq(&'{').is_some();
+                match lookahead.next() {
+                    Some('1'..='9' | '@' | '*') => return true,
+                    // `${#name}` is the length of a variable
+                    Some('#') if !braced || lookahead.next() == Some('}') => return true,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Translate `name='value'`, forwarding the arguments after the aliased text
+fn parse_alias(definition: &str) -> Option<Imported> {
+    let words = split_words(definition)?;
+    let (name, value) = words.first()?.split_once('=')?;
+    let value = unquote(value)?;
+    if value.trim().is_empty() {
+        return None;
+    }
+
+    let mut warnings = vec![
+        "arguments are forwarded through $args, split on spaces, and the ones starting with '-' are parsed as options".to_string(),
+    ];
+    if words.len() > 1 {
+        warnings.push("only the first alias of the line is imported".to_string());
+    }
+    Some(Imported {
+        name: name.to_string(),
+        description: None,
+        arguments: vec!["*args".to_string()],
+        raw_args: false,
+        lines: vec![format!("{} $args", value.trim_end())],
+        warnings,
+    })
+}
+
+/// Split shell text on the whitespace outside quotes, keeping the quotes
+fn split_words(text: &str) -> Option<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let (mut single, mut double) = (false, false);
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if !single => {
+                word.push(c);
+                word.push(chars.next()?);
+                continue;
+            }
+            '\'' if !double => single = !single,
+            '"' if !single => double = !double,
+            c if c.is_whitespace() && !single && !double => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        word.push(c);
+    }
+    if single || double {
+        return None;
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    Some(words)
+}
+
+/// Remove the quotes of a shell word, like the shell does
+fn unquote(word: &str) -> Option<String> {
+    let mut output = String::new();
+    let (mut single, mut double) = (false, false);
+    let mut chars = word.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if !double => single = !single,
+            '"' if !single => double = !double,
+            '\\' if !single => {
+                let next = chars.next()?;
+                if double && !"\"\\$`".contains(next) {
+                    output.push('\\');
+                }
+                output.push(next);
+            }
+            c => output.push(c),
+        }
+    }
+    (!single && !double).then_some(output)
+}
+
+fn to_kdl(command: &Imported) -> String {
+    let mut output = String::new();
+    for warning in &command.warnings {
+        output.push_str(&format!("// eashy import: {}\n", warning));
+    }
+    if let Some(description) = &command.description {
+        output.push_str(&format!("({}) \\\n", KdlValue::String(description.clone())));
+    }
+    let mut header = vec![KdlIdentifier::from(command.name.as_str()).to_string()];
+    header.extend(command.arguments.iter().cloned());
+    output.push_str(&format!("{} {{\n", header.join(" ")));
+    if command.raw_args {
+        output.push_str("    raw-args\n");
+    }
+
+    for line in &command.lines {
+        match to_kdl_line(line) {
+            Some(kdl_line) => output.push_str(&format!("    {}\n", kdl_line)),
+            None => output.push_str(&format!(
+                "    // eashy import: could not translate: {}\n",
+                line
+            )),
+        }
+    }
+    output.push_str("}\n");
+    output
+}
+
+/// Write a shell line as a KDL node that eashy reads back as the same line:
+/// words as entries when possible, else the whole line as the node name
+fn to_kdl_line(line: &str) -> Option<String> {
+    let as_entries = split_words(line).and_then(|words| {
+        let (first, rest) = words.split_first()?;
+        let mut parts = vec![KdlIdentifier::from(first.as_str()).to_string()];
+        parts.extend(rest.iter().cloned());
+        Some(parts.join(" "))
+    });
+    let as_name = KdlIdentifier::from(line).to_string();
+
+    [as_entries, Some(as_name)]
+        .into_iter()
+        .flatten()
+        .find(|candidate| reads_back_as(candidate, line))
+}
+
+fn reads_back_as(kdl_line: &str, line: &str) -> bool {
+    let Ok(commands) = eashy::parse_str(&format!("check {{\n{}\n}}", kdl_line)) else {
+        return false;
+    };
+    matches!(&commands[0].children, Children::Body(lines) if lines == &[line])
+}
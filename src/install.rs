@@ -0,0 +1,236 @@
+use anyhow::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::Cli;
+
+const BLOCK_START: &str = "# >>> eashy >>>";
+const BLOCK_END: &str = "# <<< eashy <<<";
+/// Number of unchanged lines shown around changes in dry-run diffs
+const DIFF_CONTEXT: usize = 3;
+
+/// Shells supported by the generated script, with their RC file relative to the home directory
+const SHELLS: [(&str, &str); 2] = [("bash", ".bashrc"), ("zsh", ".zshrc")];
+
+/// Add the block sourcing the script to the RC file of every detected shell
+pub fn install(script: &Path, dry_run: bool, quiet: bool) -> Result<(), Error> {
+    let block = get_block(script);
+    for rc_file in get_rc_files()? {
+        let content = read_rc_file(&rc_file)?;
+        let new_content = match find_rc_block(&rc_file, &content)? {
+            Some((start, end)) => format!("{}{}{}", &content[..start], block, &content[end..]),
+            None if content.is_empty() => block.clone(),
+            None if content.ends_with('\n') => {
+                format!("{}\n{}", content, block)
+            }
+            None => format!("{}\n\n{}", content, block),
+        };
+        update_rc_file(&rc_file, &content, &new_content, dry_run, quiet)?;
+    }
+    Ok(())
+}
+
+/// Remove the block added by `install` from every RC file
+pub fn uninstall(dry_run: bool, quiet: bool) -> Result<(), Error> {
+    for rc_file in get_rc_files()? {
+        let content = read_rc_file(&rc_file)?;
+        let Some((start, end)) = find_rc_block(&rc_file, &content)? else {
+            continue;
+        };
+        // Also remove the empty line added before the block
+        let start = if content[..start].ends_with("\n\n") {
+            start - 1
+        } else {
+            start
+        };
+        let new_content = format!("{}{}", &content[..start], &content[end..]);
+        update_rc_file(&rc_file, &content, &new_content, dry_run, quiet)?;
+    }
+    Ok(())
+}
+
+/// Get the RC file of the user shell, and of every other supported shell already configured
+pub fn get_rc_files() -> Result<Vec<PathBuf>, Error> {
+    let home = dirs::home_dir().ok_or_else(|| Error::msg("Failed to find the home directory"))?;
+    let shell = Cli::get_shell();
+
+    let rc_files: Vec<PathBuf> = SHELLS
+        .iter()
+        .map(|(name, rc)| (shell.ends_with(name), home.join(rc)))
+        .filter(|(current, rc_file)| *current || rc_file.exists())
+        .map(|(_, rc_file)| rc_file)
+        .collect();
+    if rc_files.is_empty() {
+        return Err(Error::msg(format!(
+            "No supported shell found (bash or zsh), current shell is '{}'",
+            shell
+        )));
+    }
+    Ok(rc_files)
+}
+
+/// Check if an RC file sources the script, either with the eashy block or
+/// with a `source` or `.` line, whatever the quoting and home directory notation
+pub fn is_sourced(content: &str, script: &Path) -> bool {
+    if find_block(content).is_some() {
+        return true;
+    }
+
+    let home = dirs::home_dir().unwrap_or_default();
+    content.lines().any(|line| {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        words.windows(2).any(|pair| {
+            if pair[0] != "source" && pair[0] != "." {
+                return false;
+            }
+            let path = pair[1].trim_end_matches(';').trim_matches(['"', '\'']);
+            let path = ["~/", "$HOME/", "${HOME}/"]
+                .iter()
+                .find_map(|prefix| path.strip_prefix(prefix))
+                .map_or_else(|| PathBuf::from(path), |rest| home.join(rest));
+            path == script
+        })
+    })
+}
+
+/// Get the marker block sourcing the script
+fn get_block(script: &Path) -> String {
+    // Keep the home directory as $HOME, so the RC file stays portable
+    let path = match dirs::home_dir().and_then(|home| script.strip_prefix(home).ok()) {
+        Some(relative) => format!("$HOME/{}", relative.display()),
+        None => script.display().to_string(),
+    };
+    format!(
+        "{BLOCK_START}\n\
+         # Added by `eashy install`, remove it with `eashy uninstall`\n\
+         [ -f \"{path}\" ] && . \"{path}\"\n\
+         {BLOCK_END}\n"
+    )
+}
+
+/// Find the marker block of an RC file, failing if it has no end marker
+/// rather than adding a second block
+fn find_rc_block(rc_file: &Path, content: &str) -> Result<Option<(usize, usize)>, Error> {
+    match find_block(content) {
+        None if content.contains(BLOCK_START) => Err(Error::msg(format!(
+            "RC file '{}' has a '{}' line without '{}', fix or remove the block by hand",
+            rc_file.display(),
+            BLOCK_START,
+            BLOCK_END
+        ))),
+        block => Ok(block),
+    }
+}
+
+/// Find the byte range of the marker block, including its last line break
+fn find_block(content: &str) -> Option<(usize, usize)> {
+    let start = content.find(BLOCK_START)?;
+    let end = start + content[start..].find(BLOCK_END)? + BLOCK_END.len();
+    let end = if content[end..].starts_with('\n') {
+        end + 1
+    } else {
+        end
+    };
+    Some((start, end))
+}
+
+fn read_rc_file(rc_file: &Path) -> Result<String, Error> {
+    if !rc_file.exists() {
+        return Ok(String::new());
+    }
+    fs::read_to_string(rc_file).map_err(|e| {
+        Error::msg(format!(
+            "Failed to read RC file '{}': {}",
+            rc_file.display(),
+            e
+        ))
+    })
+}
+
+/// Write the new RC file content after a backup of the original, kept from
+/// the first change on, or only print the diff on dry runs
+fn update_rc_file(
+    rc_file: &Path,
+    content: &str,
+    new_content: &str,
+    dry_run: bool,
+    quiet: bool,
+) -> Result<(), Error> {
+    if content == new_content {
+        if !quiet {
+            println!("✅ {} is already up to date", rc_file.display());
+        }
+        return Ok(());
+    }
+
+    if dry_run {
+        print!("{}", get_diff(rc_file, content, new_content));
+        return Ok(());
+    }
+
+    let backup = PathBuf::from(format!("{}.eashy.bak", rc_file.display()));
+    let backed_up = rc_file.exists() && !backup.exists();
+    if backed_up {
+        fs::copy(rc_file, &backup).map_err(|e| {
+            Error::msg(format!(
+                "Failed to back up '{}' to '{}': {}",
+                rc_file.display(),
+                backup.display(),
+                e
+            ))
+        })?;
+    }
+    fs::write(rc_file, new_content).map_err(|e| {
+        Error::msg(format!(
+            "Failed to write RC file '{}': {}",
+            rc_file.display(),
+            e
+        ))
+    })?;
+
+    if !quiet {
+        println!("✅ Updated {}", rc_file.display());
+        if backed_up {
+            println!("   Original file saved as {}", backup.display());
+        }
+    }
+    Ok(())
+}
+
+/// Get a unified diff of a single change, which is all install and uninstall do
+fn get_diff(rc_file: &Path, content: &str, new_content: &str) -> String {
+    let old: Vec<&str> = content.lines().collect();
+    let new: Vec<&str> = new_content.lines().collect();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let start = prefix.saturating_sub(DIFF_CONTEXT);
+    let old_end = std::cmp::min(old.len(), old.len() - suffix + DIFF_CONTEXT);
+    let new_end = std::cmp::min(new.len(), new.len() - suffix + DIFF_CONTEXT);
+
+    let mut diff = format!("--- {0}\n+++ {0}\n", rc_file.display());
+    // Empty ranges are numbered after the line preceding them
+    let range = |end: usize| {
+        let first = if end == start { start } else { start + 1 };
+        format!("{},{}", first, end - start)
+    };
+    diff.push_str(&format!("@@ -{} +{} @@\n", range(old_end), range(new_end)));
+    for line in &old[start..prefix] {
+        diff.push_str(&format!(" {}\n", line));
+    }
+    for line in &old[prefix..old.len() - suffix] {
+        diff.push_str(&format!("-{}\n", line));
+    }
+    for line in &new[prefix..new.len() - suffix] {
+        diff.push_str(&format!("+{}\n", line));
+    }
+    for line in &old[old.len() - suffix..old_end] {
+        diff.push_str(&format!(" {}\n", line));
+    }
+    diff
+}
@@ -0,0 +1,142 @@
+//! JSON export of the resolved command tree, for editors and other tools
+//!
+//! Unlike the definitions read by [`crate::formats`], every command has its
+//! path, the name of its shell function and its location, and every argument
+//! is resolved to its kind, flag and default value.
+
+use anyhow::Error;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::conditions::Condition;
+use crate::formats::{self, DefaultValue};
+use crate::parser::{
+    self, ArgPrefix, Argument, Children, CmdPrefix, Command, Example, Hooks, Location,
+};
+use kdl::KdlValue;
+
+#[derive(Serialize)]
+struct Document<'a> {
+    commands: Vec<CommandDump<'a>>,
+}
+
+#[derive(Serialize)]
+struct CommandDump<'a> {
+    name: &'a str,
+    path: &'a [String],
+    function: String,
+    description: Option<&'a str>,
+    prefix: &'a CmdPrefix,
+    usage: String,
+    arguments: Vec<ArgumentDump<'a>>,
+    subcommands: Vec<CommandDump<'a>>,
+    body: &'a [String],
+    requires: &'a [Condition],
+    hooks: &'a Hooks,
+    cwd: Option<&'a str>,
+    #[serde(serialize_with = "formats::serialize_env")]
+    env: &'a [(String, String)],
+    affects_caller: bool,
+    raw_args: bool,
+    examples: &'a [Example],
+    location: Option<LocationDump<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ArgumentKind {
+    Positional,
+    Option,
+    Flag,
+}
+
+#[derive(Serialize)]
+struct ArgumentDump<'a> {
+    name: &'a str,
+    kind: ArgumentKind,
+    /// How the option is given, like `--name` or `-n`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flag: Option<String>,
+    help: &'a str,
+    prefix: &'a ArgPrefix,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default: Option<DefaultValue>,
+}
+
+#[derive(Serialize)]
+struct LocationDump<'a> {
+    file: Option<&'a Path>,
+    line: usize,
+    column: usize,
+}
+
+/// Serialize the commands as pretty-printed JSON
+pub fn generate_json(commands: &[Command]) -> Result<String, Error> {
+    let document = Document {
+        commands: commands.iter().map(dump_command).collect(),
+    };
+    let mut output = serde_json::to_string_pretty(&document)
+        .map_err(|e| Error::msg(format!("Failed to export the commands as JSON: {}", e)))?;
+    output.push('\n');
+    Ok(output)
+}
+
+fn dump_command(command: &Command) -> CommandDump<'_> {
+    let (subcommands, body): (Vec<CommandDump>, &[String]) = match &command.children {
+        Children::Subcmds(subcommands) => (
+            subcommands.iter().map(dump_command).collect(),
+            &command.default_body,
+        ),
+        Children::Body(lines) => (Vec::new(), lines),
+    };
+    CommandDump {
+        name: &command.name,
+        path: &command.path,
+        function: command.get_mangled_function_name(),
+        description: command.description.as_deref(),
+        prefix: &command.prefix,
+        usage: parser::strip_colors(&command.get_usage_string()),
+        arguments: command.arguments.iter().map(dump_argument).collect(),
+        subcommands,
+        body,
+        requires: &command.requires,
+        hooks: &command.hooks,
+        cwd: command.cwd.as_deref(),
+        env: &command.env,
+        affects_caller: command.affects_caller,
+        raw_args: command.raw_args,
+        examples: &command.examples,
+        location: command.location.as_ref().map(dump_location),
+    }
+}
+
+fn dump_argument(arg: &Argument) -> ArgumentDump<'_> {
+    let get_flag = || {
+        if arg.name.len() == 1 {
+            format!("-{}", arg.name)
+        } else {
+            format!("--{}", arg.name)
+        }
+    };
+    let (kind, flag) = match &arg.option {
+        None => (ArgumentKind::Positional, None),
+        Some(KdlValue::Bool(_)) => (ArgumentKind::Flag, Some(get_flag())),
+        Some(_) => (ArgumentKind::Option, Some(get_flag())),
+    };
+    ArgumentDump {
+        name: &arg.name,
+        kind,
+        flag,
+        help: &arg.help,
+        prefix: &arg.prefix,
+        default: arg.option.clone().map(Into::into),
+    }
+}
+
+fn dump_location(location: &Location) -> LocationDump<'_> {
+    LocationDump {
+        file: location.file.as_deref(),
+        line: location.line,
+        column: location.column,
+    }
+}
@@ -0,0 +1,252 @@
+//! Generate shell functions with subcommands, help and completion from
+//! command definitions.
+//!
+//! Definitions are usually written in KDL, and parsed with [`parse_str`] or
+//! [`parse_file`] into a tree of [`Command`]s. JSON, YAML and TOML are also
+//! supported (see [`formats`]). That tree can also be built
+//! with [`Command::builder`] (see [`builder`]) or modified by other programs, and
+//! rendered with [`generate`]:
+//!
+//! ```
+//! use eashy::{Children, Target};
+//!
+//! let mut commands = eashy::parse_str(r#"hello name { echo "Hello $name"; }"#)?;
+//! if let Children::Body(lines) = &mut commands[0].children {
+//!     lines.push("echo \"Bye $name\"".to_string());
+//! }
+//!
+//! let script = eashy::generate(&commands, Target::Shell)?;
+//! assert!(script.contains("hello() {"));
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+#![warn(missing_docs)]
+
+use anyhow::Error;
+use kdl::KdlDocument;
+use std::fs;
+use std::path::Path;
+
+pub mod builder;
+mod conditions;
+pub mod docs_generator;
+pub mod formats;
+pub mod json_generator;
+pub mod parser;
+pub mod shell_generator;
+mod tasks;
+
+pub use builder::CommandBuilder;
+pub use conditions::Condition;
+pub use parser::{ArgPrefix, Argument, Children, CmdPrefix, Command, Example, Hooks, Location};
+
+/// Output format of [`generate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// Shell functions with their completion, for bash and zsh
+    Shell,
+    /// The resolved command tree, see [`json_generator`]
+    Json,
+    /// Reference documentation in Markdown, see [`docs_generator`]
+    Markdown,
+    /// Reference documentation as a man page, see [`docs_generator`]
+    Man,
+    /// Reference documentation as a standalone HTML page, see [`docs_generator`]
+    Html,
+}
+
+/// Input format of [`parse_str_as`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// KDL nodes, see [`parse_str`]
+    Kdl,
+    /// JSON, see [`formats`]
+    Json,
+    /// YAML, see [`formats`]
+    Yaml,
+    /// TOML, see [`formats`]
+    Toml,
+}
+
+impl Format {
+    /// Get the format of a file from its extension, if supported
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        match path.as_ref().extension()?.to_str()? {
+            "kdl" => Some(Format::Kdl),
+            "json" => Some(Format::Json),
+            "yaml" | "yml" => Some(Format::Yaml),
+            "toml" => Some(Format::Toml),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Format::Kdl => "KDL",
+            Format::Json => "JSON",
+            Format::Yaml => "YAML",
+            Format::Toml => "TOML",
+        })
+    }
+}
+
+/// Parse and validate the top-level commands of a KDL document
+///
+/// Task files imported with `from-make`, `from-just` or `from-npm`, and
+/// working directories, are relative to the current directory, see
+/// [`parse_source`] otherwise.
+pub fn parse_str(kdl: &str) -> Result<Vec<Command>, Error> {
+    Ok(parse_content(kdl, Format::Kdl, None)?.0)
+}
+
+/// Parse and validate a document, with the relative paths of its commands
+/// resolved from its directory
+fn parse_content(
+    content: &str,
+    format: Format,
+    dir: Option<&Path>,
+) -> Result<(Vec<Command>, Vec<String>), Error> {
+    let (mut commands, warnings) = match format {
+        Format::Kdl => parse_kdl(content, dir)?,
+        format => {
+            let document: formats::Document = match format {
+                Format::Json => serde_json::from_str(content)?,
+                Format::Yaml => serde_yaml::from_str(content)?,
+                _ => toml::from_str(content)?,
+            };
+            parser::validate(&document.commands)?;
+            (document.commands, Vec::new())
+        }
+    };
+    for command in &mut commands {
+        command.resolve_cwd(dir);
+    }
+    Ok((commands, warnings))
+}
+
+fn parse_kdl(kdl: &str, dir: Option<&Path>) -> Result<(Vec<Command>, Vec<String>), Error> {
+    let doc: KdlDocument = kdl.parse()?;
+
+    let mut context = parser::Context {
+        source: kdl,
+        dir,
+        ..Default::default()
+    };
+    // Argument sets and variables can be used before being defined
+    let mut nodes = Vec::new();
+    for node in doc.nodes() {
+        if let Some((name, arguments)) = parser::parse_argument_set(node) {
+            if context
+                .argument_sets
+                .insert(name.clone(), arguments)
+                .is_some()
+            {
+                return Err(Error::msg(format!(
+                    "argument set '{}' is defined twice",
+                    name
+                )));
+            }
+        } else if let Some(variables) = parser::parse_variables(node) {
+            for (name, value) in variables {
+                // Values can use the variables defined before them
+                let value = context.interpolate(&value, &[], node.name().span().offset());
+                context.variables.insert(name.to_string(), value);
+            }
+        } else if conditions::is_included(node) {
+            nodes.push(node);
+        }
+    }
+
+    let commands = nodes
+        .iter()
+        .map(|node| Command::parse_in(node, None, &context))
+        .collect::<Result<Vec<Command>, Error>>()?;
+    parser::validate(&commands)?;
+
+    let mut warnings = context.warnings.into_inner();
+    let mut unused: Vec<&String> = context
+        .argument_sets
+        .keys()
+        .filter(|name| !context.used_sets.borrow().contains(*name))
+        .collect();
+    unused.sort();
+    for name in unused {
+        warnings.push(format!("argument set '{}' is never used", name));
+    }
+    Ok((commands, warnings))
+}
+
+/// Parse and validate the top-level commands of a document in any format
+///
+/// Relative working directories are relative to the current directory, see
+/// [`parse_source`] otherwise.
+pub fn parse_str_as(content: &str, format: Format) -> Result<Vec<Command>, Error> {
+    Ok(parse_content(content, format, None)?.0)
+}
+
+/// Read, parse and validate the top-level commands of a file, in the format
+/// of its extension, KDL by default
+pub fn parse_file(path: impl AsRef<Path>) -> Result<Vec<Command>, Error> {
+    let path = path.as_ref();
+    let format = Format::from_path(path).unwrap_or(Format::Kdl);
+    let content = fs::read_to_string(path).map_err(|e| {
+        Error::msg(format!(
+            "Failed to read {} file '{}': {}",
+            format,
+            path.display(),
+            e
+        ))
+    })?;
+    parse_source(&content, path)
+}
+
+/// Parse and validate the top-level commands of the content of a file, in the
+/// format of its extension, KDL by default
+///
+/// Task files and working directories are relative to the directory of the file.
+pub fn parse_source(content: &str, path: impl AsRef<Path>) -> Result<Vec<Command>, Error> {
+    Ok(parse_source_with_warnings(content, path)?.0)
+}
+
+/// Same as [`parse_source`], also returning warnings about KDL constructs
+/// that are parsed in a way the author may not expect, like properties of
+/// body lines with a type annotation
+pub fn parse_source_with_warnings(
+    content: &str,
+    path: impl AsRef<Path>,
+) -> Result<(Vec<Command>, Vec<String>), Error> {
+    let path = path.as_ref();
+    let format = Format::from_path(path).unwrap_or(Format::Kdl);
+    let dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let (mut commands, warnings) = parse_content(content, format, Some(dir)).map_err(|e| {
+        Error::msg(format!(
+            "Failed to parse {} file '{}': {}",
+            format,
+            path.display(),
+            e
+        ))
+    })?;
+    for command in &mut commands {
+        command.set_source_file(path);
+    }
+    Ok((commands, warnings))
+}
+
+/// Render commands in the target format
+///
+/// Fails when the JSON export cannot represent the tree, like source files
+/// with a path that is not UTF-8.
+pub fn generate(commands: &[Command], target: Target) -> Result<String, Error> {
+    Ok(match target {
+        Target::Shell => shell_generator::generate_script(commands, &[]),
+        Target::Json => json_generator::generate_json(commands)?,
+        Target::Markdown => docs_generator::generate_markdown(commands),
+        Target::Man => docs_generator::generate_man(commands),
+        Target::Html => docs_generator::generate_html(commands),
+    })
+}
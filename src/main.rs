@@ -1,18 +1,54 @@
 use anyhow::Error;
-use kdl::KdlDocument;
+use eashy::parser::{self, Children, Command};
+use eashy::shell_generator::{self, SourceFile};
+use eashy::{Format, Target};
+use std::collections::HashMap;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
-use crate::cli::Cli;
-use crate::parser::Command;
+use crate::cli::{Cli, Commands, DocsFormat, DumpFormat};
 
+/// Template written by `eashy init`
+const DEFAULT_KDL: &str = include_str!("../default.kdl");
+
+mod allow;
 mod cli;
-mod parser;
-mod shell_generator;
+mod import;
+mod install;
+mod runner;
+mod watch;
 
 fn main() -> Result<(), Error> {
     let cli = Cli::parse();
-    let input_file = cli.get_input_file()?;
+
+    match &cli.command {
+        Some(Commands::Generate) | None => generate(&cli),
+        Some(Commands::Check) => check(&cli),
+        Some(Commands::List) => list(&cli),
+        Some(Commands::Show { path }) => show(&cli, path),
+        Some(Commands::Init) => init(&cli),
+        Some(Commands::Install { dry_run }) => {
+            let Some(script) = cli.get_output_file()? else {
+                return Err(Error::msg("Cannot install a script written to stdout"));
+            };
+            install::install(&std::path::absolute(script)?, *dry_run, cli.quiet)
+        }
+        Some(Commands::Uninstall { dry_run }) => install::uninstall(*dry_run, cli.quiet),
+        Some(Commands::Allow { file }) => allow::allow(file.as_deref(), cli.quiet),
+        Some(Commands::Test { shell }) => runner::run(&load(&cli)?.0, shell, cli.quiet),
+        Some(Commands::Watch) => watch::watch(&cli),
+        Some(Commands::Import { file }) => {
+            print!("{}", import::import(file, cli.quiet)?);
+            Ok(())
+        }
+        Some(Commands::Docs { format, dir }) => docs(&cli, *format, dir.as_deref()),
+        Some(Commands::Dump { format }) => dump(&cli, *format),
+    }
+}
+
+/// Generate the shell script once and write it to its output
+fn generate(cli: &Cli) -> Result<(), Error> {
     let output_file = cli.get_output_file()?;
 
     // Create directory for output file if needed
@@ -22,31 +58,7 @@ fn main() -> Result<(), Error> {
         std::fs::create_dir_all(parent)?;
     }
 
-    // Read and parse the KDL file
-    let doc: KdlDocument = fs::read_to_string(&input_file)
-        .map_err(|e| {
-            Error::msg(format!(
-                "Failed to read KDL file '{}': {}",
-                input_file.display(),
-                e
-            ))
-        })?
-        .parse()
-        .map_err(|e| {
-            Error::msg(format!(
-                "Failed to parse KDL file '{}': {}",
-                input_file.display(),
-                e
-            ))
-        })?;
-
-    let commands = doc
-        .nodes()
-        .iter()
-        .map(|node| Command::parse(node, None))
-        .collect::<Vec<Command>>();
-
-    let output = shell_generator::generate_script(&commands);
+    let output = build_script(cli)?;
 
     // Write output to stdout or file
     if cli.is_stdout_output() {
@@ -56,16 +68,256 @@ fn main() -> Result<(), Error> {
             .map_err(|e| Error::msg(format!("Failed to flush stdout: {}", e)))?;
     } else {
         let file = output_file.unwrap();
-        fs::write(&file, output).map_err(|e| {
-            Error::msg(format!(
-                "Failed to write output file '{}': {}",
-                file.display(),
-                e
-            ))
-        })?;
-
+        write_output(&file, &output)?;
         cli.print_success_message(&file);
     }
 
     Ok(())
 }
+
+/// Print the documentation to stdout, or write a file per top-level command
+fn docs(cli: &Cli, format: DocsFormat, dir: Option<&Path>) -> Result<(), Error> {
+    let (commands, _) = load(cli)?;
+    let target = match format {
+        DocsFormat::Markdown => Target::Markdown,
+        DocsFormat::Man => Target::Man,
+        DocsFormat::Html => Target::Html,
+    };
+
+    let Some(dir) = dir else {
+        print!("{}", eashy::generate(&commands, target)?);
+        return Ok(());
+    };
+    fs::create_dir_all(dir)
+        .map_err(|e| Error::msg(format!("Failed to create '{}': {}", dir.display(), e)))?;
+    for command in &commands {
+        let file = dir.join(format!("{}.{}", command.name, format.get_extension()));
+        write_output(
+            &file,
+            &eashy::generate(std::slice::from_ref(command), target)?,
+        )?;
+        if !cli.quiet {
+            println!("✅ Documentation written to {}", file.display());
+        }
+    }
+    Ok(())
+}
+
+/// Print the resolved command tree to stdout
+fn dump(cli: &Cli, format: DumpFormat) -> Result<(), Error> {
+    let (commands, _) = load(cli)?;
+    let target = match format {
+        DumpFormat::Json => Target::Json,
+    };
+    print!("{}", eashy::generate(&commands, target)?);
+    Ok(())
+}
+
+/// Parse and validate the KDL files, without generating anything
+fn check(cli: &Cli) -> Result<(), Error> {
+    let (_, sources) = load(cli)?;
+    if !cli.quiet {
+        println!("✅ No errors found in {} KDL file(s)", sources.len());
+    }
+    Ok(())
+}
+
+/// Print the tree of every command, with their description
+fn list(cli: &Cli) -> Result<(), Error> {
+    let (commands, _) = load(cli)?;
+    let mut lines = Vec::new();
+    collect_tree_lines(&commands, None, &mut lines);
+
+    let width = lines
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (label, description) in lines {
+        println!(
+            "{}",
+            format!("{:width$}  {}", label, description).trim_end()
+        );
+    }
+    Ok(())
+}
+
+fn collect_tree_lines(
+    commands: &[Command],
+    indent: Option<&str>,
+    lines: &mut Vec<(String, String)>,
+) {
+    for (index, command) in commands.iter().enumerate() {
+        let last = index == commands.len() - 1;
+        let (label, child_indent) = match indent {
+            None => (command.name.clone(), String::new()),
+            Some(indent) if last => (
+                format!("{}└── {}", indent, command.name),
+                format!("{}    ", indent),
+            ),
+            Some(indent) => (
+                format!("{}├── {}", indent, command.name),
+                format!("{}│   ", indent),
+            ),
+        };
+        lines.push((label, command.description.clone().unwrap_or_default()));
+
+        if let Children::Subcmds(subcommands) = &command.children {
+            collect_tree_lines(subcommands, Some(&child_indent), lines);
+        }
+    }
+}
+
+/// Print the help of one command
+fn show(cli: &Cli, path: &[String]) -> Result<(), Error> {
+    let (commands, _) = load(cli)?;
+    let command = parser::find_command(&commands, path)
+        .ok_or_else(|| Error::msg(format!("Command '{}' not found", path.join(" "))))?;
+
+    print!("{}", render_colors(&command.get_help_string()));
+    Ok(())
+}
+
+/// Turn the escaped colors meant for printf into terminal colors, or remove
+/// them when stdout is not a terminal
+fn render_colors(s: &str) -> String {
+    if io::stdout().is_terminal() {
+        s.replace("\\033", "\x1b")
+    } else {
+        parser::strip_colors(s)
+    }
+}
+
+/// Write the default KDL file, unless it already exists
+fn init(cli: &Cli) -> Result<(), Error> {
+    let file = cli.get_init_file()?;
+    if file.exists() {
+        if !cli.quiet {
+            println!("ℹ️  {} already exists, nothing to do", file.display());
+        }
+        return Ok(());
+    }
+
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&file, DEFAULT_KDL).map_err(|e| {
+        Error::msg(format!(
+            "Failed to write KDL file '{}': {}",
+            file.display(),
+            e
+        ))
+    })?;
+
+    if !cli.quiet {
+        println!("✅ Default KDL file written at: {}", file.display());
+        println!("   Edit it, then run `eashy` to generate your shell functions");
+    }
+    Ok(())
+}
+
+/// Parse and validate every input file
+fn load(cli: &Cli) -> Result<(Vec<Command>, Vec<SourceFile>), Error> {
+    let input_files = cli.get_input_files()?;
+    load_commands(&input_files, cli.keep_going, cli.quiet)
+}
+
+/// Parse the input files and render the whole shell script
+fn build_script(cli: &Cli) -> Result<String, Error> {
+    let (commands, sources) = load(cli)?;
+
+    let mut output = shell_generator::generate_script(&commands, &sources);
+    if cli.project || cli.reload {
+        let eashy_bin = std::env::current_exe()
+            .map_err(|e| Error::msg(format!("Failed to locate the eashy binary: {}", e)))?;
+        if cli.project {
+            output.push_str(&shell_generator::generate_project_hook(&eashy_bin));
+        }
+        if cli.reload {
+            let Some(output_file) = cli.get_output_file()? else {
+                return Err(Error::msg("Auto reload needs an output file, not stdout"));
+            };
+            output.push_str(&shell_generator::generate_reload_hook(
+                &eashy_bin,
+                &cli.get_reload_args(&output_file)?,
+                &std::path::absolute(&output_file)?,
+                &std::path::absolute(cli.get_input_file()?)?,
+                &sources,
+            ));
+        }
+    }
+    Ok(output)
+}
+
+fn write_output(file: &Path, output: &str) -> Result<(), Error> {
+    fs::write(file, output).map_err(|e| {
+        Error::msg(format!(
+            "Failed to write output file '{}': {}",
+            file.display(),
+            e
+        ))
+    })
+}
+
+/// Parse and merge the commands of every input file, in order
+///
+/// Also returns the files read, to record them in the script header
+fn load_commands(
+    input_files: &[PathBuf],
+    keep_going: bool,
+    quiet: bool,
+) -> Result<(Vec<Command>, Vec<SourceFile>), Error> {
+    let mut commands = Vec::new();
+    let mut sources = Vec::new();
+    let mut origins: HashMap<String, &Path> = HashMap::new();
+
+    for input_file in input_files {
+        let format = Format::from_path(input_file).unwrap_or(Format::Kdl);
+        let parsed = fs::read_to_string(input_file)
+            .map_err(|e| {
+                Error::msg(format!(
+                    "Failed to read {} file '{}': {}",
+                    format,
+                    input_file.display(),
+                    e
+                ))
+            })
+            .and_then(|content| {
+                sources.push(SourceFile::new(input_file, &content));
+                eashy::parse_source_with_warnings(&content, input_file)
+            });
+        let file_commands = match parsed {
+            Ok((file_commands, warnings)) => {
+                if !quiet {
+                    for warning in warnings {
+                        eprintln!("⚠️  {}: {}", input_file.display(), warning);
+                    }
+                }
+                file_commands
+            }
+            Err(e) if keep_going => {
+                eprintln!("⚠️  {}, skipping it", e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        let source_file = std::path::absolute(input_file)?;
+        for mut command in file_commands {
+            command.set_source_file(&source_file);
+            if let Some(origin) = origins.insert(command.name.clone(), input_file)
+                && origin != input_file
+            {
+                return Err(Error::msg(format!(
+                    "Command '{}' is defined in both '{}' and '{}'",
+                    command.name,
+                    origin.display(),
+                    input_file.display()
+                )));
+            }
+            commands.push(command);
+        }
+    }
+
+    Ok((commands, sources))
+}
@@ -1,22 +1,40 @@
+//! Command model, parsed from KDL nodes
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use kdl::{KdlDocument, KdlEntry, KdlIdentifier, KdlNode, KdlValue};
+use anyhow::Error;
+use kdl::{KdlEntry, KdlIdentifier, KdlNode, KdlValue};
+use serde::{Deserialize, Serialize};
 
-pub const TITLE: &str = "\\033[1;32m"; // Bold green
-pub const COMMAND: &str = "\\033[1;36m"; // Bold cyan
-pub const OPTIONS: &str = "\\033[0;36m"; // Normal cyan
-pub const ERROR: &str = "\\033[1;31m"; // Bold red
-pub const RESET: &str = "\\033[0m"; // Reset
+use crate::conditions::{self, Condition, When};
+use crate::tasks::{self, TaskRunner};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) const TITLE: &str = "\\033[1;32m"; // Bold green
+pub(crate) const COMMAND: &str = "\\033[1;36m"; // Bold cyan
+pub(crate) const OPTIONS: &str = "\\033[0;36m"; // Normal cyan
+pub(crate) const ERROR: &str = "\\033[1;31m"; // Bold red
+pub(crate) const RESET: &str = "\\033[0m"; // Reset
+
+/// How many values a positional argument takes, from its name prefix
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ArgPrefix {
+    /// `*arg`: zero or more values
     ZeroMore,
+    /// `+arg`: one or more values
     OneMore,
+    /// `?arg`: zero or one value
     ZeroOne,
+    /// `arg`: exactly one value
+    #[default]
     None,
 }
 
 impl ArgPrefix {
+    /// Split an argument name into its prefix and its actual name
     pub fn extract(name: &str) -> (Self, String) {
         if let Some(first_char) = name.chars().next() {
             match first_char {
@@ -31,14 +49,21 @@ impl ArgPrefix {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// How the lines of a command body are chained, from its name prefix
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CmdPrefix {
+    /// `&cmd`: stop at the first line failing
     UntilError,
+    /// `|cmd`: stop at the first line succeeding
     UntilSuccess,
+    /// `cmd`: run every line
+    #[default]
     None,
 }
 
 impl CmdPrefix {
+    /// Split a command name into its prefix and its actual name
     pub fn extract(name: &str) -> (Self, String) {
         if let Some(first_char) = name.chars().next() {
             match first_char {
@@ -52,11 +77,20 @@ impl CmdPrefix {
     }
 }
 
-#[derive(Debug, Clone)]
+/// A positional argument or an option of a command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    from = "crate::formats::ArgumentDef",
+    into = "crate::formats::ArgumentDef"
+)]
 pub struct Argument {
+    /// Name, which is also the shell variable holding its value
     pub name: String,
+    /// Description shown in the help
     pub help: String,
+    /// Number of values taken by a positional argument
     pub prefix: ArgPrefix,
+    /// Default value of an option, `None` for positional arguments
     pub option: Option<KdlValue>,
 }
 
@@ -86,77 +120,550 @@ impl Argument {
     }
 }
 
-#[derive(Debug, Clone)]
+/// What a command runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Children {
+    /// Subcommands, dispatched from the first argument
+    #[serde(rename = "subcommands")]
     Subcmds(Vec<Command>),
+    /// Shell lines of a leaf command
     Body(Vec<String>),
 }
 
-#[derive(Debug, Clone)]
+/// Shell lines run around the body of a command and of its subcommands
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Hooks {
+    /// Run before the body, which is skipped if they fail
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub before: Vec<String>,
+    /// Run after the body succeeded
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub after: Vec<String>,
+    /// Run when the body or a hook failed, with its exit status in `$_status`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub on_error: Vec<String>,
+    /// Always run last, with the exit status in `$_status`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub finally: Vec<String>,
+}
+
+impl Hooks {
+    /// Check if there are no hooks
+    pub fn is_empty(&self) -> bool {
+        self.before.is_empty()
+            && self.after.is_empty()
+            && self.on_error.is_empty()
+            && self.finally.is_empty()
+    }
+
+    /// Combine with the hooks of a parent command, which surround these ones
+    pub fn inherit(&self, parent: &Hooks) -> Hooks {
+        let concat = |first: &[String], second: &[String]| [first, second].concat();
+        Hooks {
+            before: concat(&parent.before, &self.before),
+            after: concat(&self.after, &parent.after),
+            on_error: concat(&self.on_error, &parent.on_error),
+            finally: concat(&self.finally, &parent.finally),
+        }
+    }
+
+    /// Get the lines of a `before`, `after`, `on-error` or `finally` block
+    fn get_lines_mut(&mut self, node: &KdlNode) -> Option<&mut Vec<String>> {
+        if node.children().is_none() || !node.entries().is_empty() || node.ty().is_some() {
+            return None;
+        }
+        match node.name().value() {
+            "before" => Some(&mut self.before),
+            "after" => Some(&mut self.after),
+            "on-error" => Some(&mut self.on_error),
+            "finally" => Some(&mut self.finally),
+            _ => None,
+        }
+    }
+}
+
+/// An example invocation of a command, checked by `eashy test`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Example {
+    /// Arguments given to the command, as shell text
+    pub args: String,
+    /// Text expected in the standard output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+    /// Text expected in the standard error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<String>,
+    /// Expected exit status
+    #[serde(skip_serializing_if = "crate::formats::is_default")]
+    pub status: i32,
+}
+
+impl Example {
+    fn parse(node: &KdlNode) -> Result<Self, Error> {
+        let mut example = Example::default();
+
+        for entry in node.entries() {
+            match (entry.name().map(|n| n.value()), entry.value()) {
+                (None, KdlValue::String(args)) => example.args = args.clone(),
+                (Some("stdout"), KdlValue::String(stdout)) => example.stdout = Some(stdout.clone()),
+                (Some("stderr"), KdlValue::String(stderr)) => example.stderr = Some(stderr.clone()),
+                (Some("status"), KdlValue::Integer(status)) => {
+                    example.status = i32::try_from(*status)
+                        .map_err(|_| Error::msg(format!("invalid example status {}", status)))?
+                }
+                _ => {
+                    return Err(Error::msg(format!(
+                        "invalid example entry '{}'",
+                        entry.to_string().trim()
+                    )));
+                }
+            }
+        }
+        Ok(example)
+    }
+}
+
+/// Where a command is defined
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// File defining the command, if known
+    pub file: Option<PathBuf>,
+    /// Line of the command name, from 1
+    pub line: usize,
+    /// Column of the command name, in characters from 1
+    pub column: usize,
+}
+
+/// Document being parsed, to locate its commands and report warnings
+#[derive(Debug, Default)]
+pub(crate) struct Context<'a> {
+    /// Text of the document, empty when unknown
+    pub source: &'a str,
+    /// Directory of the document, for the relative paths of its commands
+    pub dir: Option<&'a Path>,
+    /// Constructs parsed in a way the author may not expect
+    pub warnings: RefCell<Vec<String>>,
+    /// Argument sets defined at the top level, by name
+    pub argument_sets: HashMap<String, Vec<Argument>>,
+    /// Names of the argument sets used by commands
+    pub used_sets: RefCell<HashSet<String>>,
+    /// Values of the `{{name}}` variables, defined at the top level
+    pub variables: HashMap<String, String>,
+}
+
+impl Context<'_> {
+    /// Replace the `{{name}}` variables and `{{env.NAME}}` environment
+    /// variables of a text, leaving unknown ones as is with a warning
+    ///
+    /// `\{{` is kept as a literal `{{`.
+    pub fn interpolate(&self, text: &str, path: &[String], offset: usize) -> String {
+        let mut output = String::new();
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            if rest[..start].ends_with('\\') {
+                output.push_str(&rest[..start - 1]);
+                output.push_str("{{");
+                rest = &rest[start + 2..];
+                continue;
+            }
+            output.push_str(&rest[..start]);
+            let Some(end) = rest[start..].find("}}").map(|end| start + end + 2) else {
+                // Unterminated, kept as is
+                output.push_str(&rest[start..]);
+                return output;
+            };
+            let reference = &rest[start..end];
+            let name = reference[2..reference.len() - 2].trim();
+            match name.strip_prefix("env.") {
+                Some(env) if is_shell_identifier(env) => match std::env::var(env) {
+                    Ok(value) => output.push_str(&value),
+                    Err(_) => {
+                        self.warn(
+                            path,
+                            offset,
+                            format!(
+                                "environment variable '{}' is not set, '{}' is left as is",
+                                env, reference
+                            ),
+                        );
+                        output.push_str(reference);
+                    }
+                },
+                _ if is_variable_name(name) => match self.variables.get(name) {
+                    Some(value) => output.push_str(value),
+                    None => {
+                        self.warn(
+                            path,
+                            offset,
+                            format!("unknown variable '{}' is left as is", reference),
+                        );
+                        output.push_str(reference);
+                    }
+                },
+                // Not a variable, like the `{{.Name}}` templates of other tools
+                _ => output.push_str(reference),
+            }
+            rest = &rest[end..];
+        }
+        output.push_str(rest);
+        output
+    }
+
+    /// Record a warning about a command, at an offset of the document
+    fn warn(&self, path: &[String], offset: usize, message: String) {
+        let warning = match (path.is_empty(), self.locate(offset)) {
+            (true, Some(location)) => format!("line {}: {}", location.line, message),
+            (true, None) => message,
+            (false, Some(location)) => {
+                format!("'{}', line {}: {}", path.join(" "), location.line, message)
+            }
+            (false, None) => format!("'{}': {}", path.join(" "), message),
+        };
+        self.warnings.borrow_mut().push(warning);
+    }
+
+    fn locate(&self, offset: usize) -> Option<Location> {
+        if self.source.is_empty() {
+            return None;
+        }
+        let before = self.source.get(..offset)?;
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Some(Location {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        })
+    }
+}
+
+/// A command, or a subcommand, generated as a shell function
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    from = "crate::formats::CommandDef",
+    into = "crate::formats::CommandDef"
+)]
 pub struct Command {
+    /// Name, as typed in the shell
     pub name: String,
+    /// How the body lines are chained
     pub prefix: CmdPrefix,
+    /// Description shown in the help and completion
     pub description: Option<String>,
+    /// Positional arguments and options
     pub arguments: Vec<Argument>,
+    /// Subcommands or body lines
     pub children: Children,
+    /// Body lines run when no subcommand is given, for commands with subcommands
+    pub default_body: Vec<String>,
+    /// Conditions checked when running the command and its subcommands
+    pub requires: Vec<Condition>,
+    /// Shell lines run around the body of the command and of its subcommands
+    pub hooks: Hooks,
+    /// Directory the body runs in, `git-root` being the root of the current
+    /// git repository
+    pub cwd: Option<String>,
+    /// Environment variables exported for the body, their value being
+    /// expanded by the shell
+    pub env: Vec<(String, String)>,
+    /// Run the body in the caller's shell, instead of a subshell, so that a
+    /// working directory or environment set by the command are kept
+    pub affects_caller: bool,
+    /// Give every argument to the body as is, in `"$@"`, instead of parsing
+    /// them, for commands wrapping another tool
+    pub raw_args: bool,
+    /// Names from the top-level command down to this one
     pub path: Vec<String>,
+    /// Example invocations, checked by `eashy test`
+    pub examples: Vec<Example>,
+    /// Where the command is defined, only known for KDL documents
+    pub location: Option<Location>,
 }
 
 impl Command {
-    pub fn parse(node: &KdlNode, path: Option<Vec<String>>) -> Self {
-        let (prefix, name) = CmdPrefix::extract(node.name().value());
+    /// Parse a command from its KDL node, `path` being the one of its parent command
+    pub fn parse(node: &KdlNode, path: Option<Vec<String>>) -> Result<Self, Error> {
+        Self::parse_in(node, path, &Context::default())
+    }
+
+    /// Parse a command from its KDL node, located in the document of the context
+    pub(crate) fn parse_in(
+        node: &KdlNode,
+        path: Option<Vec<String>>,
+        context: &Context,
+    ) -> Result<Self, Error> {
+        // Commands are declared as `name args...`, or `cmd name args...`
+        let (name, entries) = match get_cmd_name(node) {
+            Some(name) => (name, &node.entries()[1..]),
+            None => (node.name().value(), node.entries()),
+        };
+        let (prefix, name) = CmdPrefix::extract(name);
+        let location = context.locate(node.name().span().offset());
         let mut path = path.unwrap_or_default();
         path.push(name.clone());
-        let description = node.ty().map(|id| id.value().to_string());
-        let arguments: Vec<Argument> = node.entries().iter().map(Argument::parse).collect();
-
-        let children = if let Some(children) = node.children() {
-            // Check if this has subcommands or is a leaf command
-            let has_nested_subcommands = children.nodes().iter().any(|n| n.children().is_some());
-
-            if has_nested_subcommands {
-                // This has subcommands
-                Children::Subcmds(
-                    children
-                        .nodes()
-                        .iter()
-                        .map(|n| Self::parse(n, Some(path.clone())))
-                        .collect(),
-                )
+        let offset = node.name().span().offset();
+        let description = node
+            .ty()
+            .map(|id| context.interpolate(id.value(), &path, offset));
+        let mut arguments: Vec<Argument> = entries.iter().map(Argument::parse).collect();
+
+        // Extract the nodes describing the command itself from its children
+        let mut examples = Vec::new();
+        let mut default_body = Vec::new();
+        let mut requires = Vec::new();
+        let mut hooks = Hooks::default();
+        let mut cwd = None;
+        let mut env = Vec::new();
+        let mut affects_caller = false;
+        let mut raw_args = false;
+        let mut imported = Vec::new();
+        // Blocks read as settings of the command, which used to be subcommands
+        let mut settings_blocks = Vec::new();
+        let mut nodes = Vec::new();
+        for child in node.children().map(|c| c.nodes()).unwrap_or_default() {
+            if child.name().value() == "example" && child.children().is_none() {
+                let mut example = Example::parse(child)
+                    .map_err(|e| Error::msg(format!("'{}': {}", path.join(" "), e)))?;
+                let offset = child.name().span().offset();
+                example.args = context.interpolate(&example.args, &path, offset);
+                for text in [&mut example.stdout, &mut example.stderr]
+                    .into_iter()
+                    .flatten()
+                {
+                    *text = context.interpolate(text, &path, offset);
+                }
+                examples.push(example);
+            } else if let Some(sets) = get_used_sets(child) {
+                for set in sets {
+                    let set_arguments = context.argument_sets.get(set).ok_or_else(|| {
+                        Error::msg(format!(
+                            "'{}': unknown argument set '{}'",
+                            path.join(" "),
+                            set
+                        ))
+                    })?;
+                    arguments.extend(set_arguments.iter().cloned());
+                    context.used_sets.borrow_mut().insert(set.to_string());
+                }
+            } else if let Some(when) = When::parse(child) {
+                let when = when.map_err(|e| Error::msg(format!("'{}': {}", path.join(" "), e)))?;
+                // The other conditions were checked by the parent, see `conditions::is_included`
+                if when.runtime {
+                    requires.extend(when.conditions);
+                }
+            } else if let Some(lines) = hooks.get_lines_mut(child) {
+                settings_blocks.push((child, "a hook"));
+                let nodes: Vec<&KdlNode> = child.children().unwrap().nodes().iter().collect();
+                lines.extend(Self::parse_command_body(&nodes, &path, context));
+            } else if let Some(dir) = get_cwd(child) {
+                // Resolved with the directory of the file, see `Command::resolve_cwd`
+                let offset = child.name().span().offset();
+                cwd = Some(context.interpolate(dir, &path, offset));
+            } else if is_env_block(child) {
+                for variable in child.children().unwrap().nodes() {
+                    let name = variable.name().value();
+                    let value = match variable.entries() {
+                        [entry] if entry.name().is_none() && variable.children().is_none() => {
+                            match entry.value() {
+                                KdlValue::String(value) => value.clone(),
+                                value => value.to_string().trim_start_matches('#').to_string(),
+                            }
+                        }
+                        _ => {
+                            return Err(Error::msg(format!(
+                                "'{}': environment variable '{}' takes a single value",
+                                path.join(" "),
+                                name
+                            )));
+                        }
+                    };
+                    let offset = variable.name().span().offset();
+                    env.push((name.to_string(), context.interpolate(&value, &path, offset)));
+                }
+            } else if child.name().value() == "affects-caller"
+                && child.entries().is_empty()
+                && child.children().is_none()
+            {
+                affects_caller = true;
+            } else if child.name().value() == "raw-args"
+                && child.entries().is_empty()
+                && child.children().is_none()
+            {
+                raw_args = true;
+            } else if is_run_block(child) {
+                settings_blocks.push((child, "the default body"));
+                let lines: Vec<&KdlNode> = child.children().unwrap().nodes().iter().collect();
+                default_body.extend(Self::parse_command_body(&lines, &path, context));
+            } else if let Some(runner) = TaskRunner::from_directive(child) {
+                let location = context.locate(child.name().span().offset());
+                let mut subcommands = tasks::import(runner, child, &path, context.dir)
+                    .map_err(|e| Error::msg(format!("'{}': {}", path.join(" "), e)))?;
+                for subcommand in &mut subcommands {
+                    subcommand.location = location.clone();
+                }
+                // Keep the position of the directive among the other subcommands
+                imported.push((nodes.len(), subcommands));
             } else {
-                Children::Body(Self::parse_command_body(children))
+                nodes.push(child);
+            }
+        }
+
+        // Blocks in shell lines are dropped: the block was likely meant as
+        // a subcommand with its own subcommands, as it used to be
+        for (block, setting) in settings_blocks {
+            let nested = block.children().unwrap().nodes();
+            if let Some(nested) = nested.iter().find(|n| n.children().is_some()) {
+                let name = block.name().value();
+                context.warn(
+                    &path,
+                    block.name().span().offset(),
+                    format!(
+                        "'{}' block is {} of the command, not a subcommand, \
+                         so its '{}' block is ignored: declare it with `cmd {}` if it is one",
+                        name,
+                        setting,
+                        nested.name().value(),
+                        name
+                    ),
+                );
+            }
+        }
+
+        // Check if this has subcommands or is a leaf command
+        let has_nested_subcommands = !imported.is_empty()
+            || nodes
+                .iter()
+                .any(|n| n.children().is_some() || get_cmd_name(n).is_some());
+        let children = if has_nested_subcommands {
+            // This has subcommands
+            let mut subcommands = Vec::new();
+            let mut imported = imported.into_iter().peekable();
+            for (index, n) in nodes.iter().enumerate() {
+                while let Some((_, commands)) = imported.next_if(|(at, _)| *at == index) {
+                    subcommands.extend(commands);
+                }
+                if n.children().is_none() && get_cmd_name(n).is_none() {
+                    context.warn(
+                        &path,
+                        n.name().span().offset(),
+                        format!(
+                            "'{}' has no block, so it is a subcommand doing nothing: \
+                             declare it with `cmd {}`, or move it to a `run` block if it is a shell line",
+                            n.name().value(),
+                            n.name().value()
+                        ),
+                    );
+                }
+                if conditions::is_included(n) {
+                    subcommands.push(Self::parse_in(n, Some(path.clone()), context)?);
+                }
             }
+            subcommands.extend(imported.flat_map(|(_, commands)| commands));
+            Children::Subcmds(subcommands)
         } else {
-            Children::Body(Vec::new())
+            // Without subcommands, the default body is the body
+            let mut lines = Self::parse_command_body(&nodes, &path, context);
+            lines.append(&mut default_body);
+            Children::Body(lines)
         };
 
-        Command {
+        for arg in &mut arguments {
+            arg.help = context.interpolate(&arg.help, &path, offset);
+            if let Some(KdlValue::String(value)) = &mut arg.option {
+                *value = context.interpolate(value, &path, offset);
+            }
+        }
+
+        Ok(Command {
             name,
             prefix,
             description,
             arguments,
             children,
             path,
+            default_body,
+            requires,
+            hooks,
+            cwd,
+            env,
+            affects_caller,
+            raw_args,
+            examples,
+            location,
+        })
+    }
+
+    /// Record the file defining the command and its subcommands, in their location
+    pub fn set_source_file(&mut self, file: &Path) {
+        if let Some(location) = &mut self.location {
+            location.file = Some(file.to_path_buf());
+        }
+        if let Children::Subcmds(subcommands) = &mut self.children {
+            for subcommand in subcommands {
+                subcommand.set_source_file(file);
+            }
         }
     }
 
-    fn parse_command_body(children: &KdlDocument) -> Vec<String> {
+    /// Make the working directories of the command and its subcommands
+    /// absolute, relative ones and `kdl-dir` being relative to the directory of
+    /// the file defining them, the current one when unknown
+    ///
+    /// `git-root` and directories starting with `~` or `$` are expanded by the
+    /// shell instead.
+    pub(crate) fn resolve_cwd(&mut self, dir: Option<&Path>) {
+        if let Some(cwd) = &mut self.cwd {
+            match cwd.as_str() {
+                "git-root" => {}
+                cwd if cwd.starts_with('~') || cwd.starts_with('$') => {}
+                "kdl-dir" => *cwd = get_kdl_dir(dir).display().to_string(),
+                relative => *cwd = get_kdl_dir(dir).join(relative).display().to_string(),
+            }
+        }
+        if let Children::Subcmds(subcommands) = &mut self.children {
+            for subcommand in subcommands {
+                subcommand.resolve_cwd(dir);
+            }
+        }
+    }
+
+    /// Turn nodes into shell lines: the node name, then its entries as shell
+    /// words (see [`get_shell_word`]), or the verbatim text of `script` nodes
+    fn parse_command_body(nodes: &[&KdlNode], path: &[String], context: &Context) -> Vec<String> {
         let mut command_lines = Vec::new();
 
-        for cmd_node in children.nodes() {
-            let cmd_name = cmd_node.name().value();
-            let mut cmd_parts = vec![cmd_name.to_string()];
+        for cmd_node in nodes {
+            if let Some(script) = get_script(cmd_node) {
+                let offset = cmd_node.name().span().offset();
+                command_lines.push(context.interpolate(script, path, offset));
+                continue;
+            }
 
+            if let Some(ty) = cmd_node.ty() {
+                context.warn(
+                    path,
+                    ty.span().offset(),
+                    format!(
+                        "description '({})' of body line '{}' is ignored, \
+                         declare subcommands with `cmd {}`",
+                        ty.value(),
+                        cmd_node.name().value(),
+                        cmd_node.name().value()
+                    ),
+                );
+            }
+            let offset = cmd_node.name().span().offset();
+            let mut cmd_parts = vec![context.interpolate(cmd_node.name().value(), path, offset)];
             for entry in cmd_node.entries() {
-                // REMOVE QUOTES
-                // if let Some(s) = entry.value().as_string() {
-                //     cmd_parts.push(s.to_string());
-                // } else {
-                //     let part = entry.value().to_string();
-                //     cmd_parts.push(part);
-                // }
-                let part = entry.value().to_string();
-                cmd_parts.push(part);
+                for message in get_lossy_parts(entry) {
+                    context.warn(path, entry.span().offset(), message);
+                }
+                // Interpolated before quoting, so that values are quoted too
+                let offset = entry.span().offset();
+                let interpolate = |text: &str| context.interpolate(text, path, offset);
+                cmd_parts.push(get_shell_word(entry, &interpolate));
             }
             command_lines.push(cmd_parts.join(" "));
         }
@@ -164,6 +671,81 @@ impl Command {
         command_lines
     }
 
+    /// Check the command and its subcommands generate valid shell code
+    ///
+    /// Returns one message per error found.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let path = self.get_command_path_string();
+
+        if !is_command_name(&self.name) {
+            errors.push(format!("'{}': invalid command name", path));
+        }
+
+        let mut arg_names: Vec<&str> = Vec::new();
+        for arg in &self.arguments {
+            if !is_shell_identifier(&arg.name) {
+                errors.push(format!(
+                    "'{}': argument '{}' is not a valid shell variable name",
+                    path, arg.name
+                ));
+            }
+            if arg.option.is_some() && (arg.name == "h" || arg.name == "help") {
+                errors.push(format!(
+                    "'{}': option '{}' conflicts with the help flag",
+                    path, arg.name
+                ));
+            }
+            if arg_names.contains(&arg.name.as_str()) {
+                errors.push(format!(
+                    "'{}': argument '{}' is defined twice",
+                    path, arg.name
+                ));
+            }
+            arg_names.push(&arg.name);
+        }
+
+        for (name, _) in &self.env {
+            if !is_shell_identifier(name) {
+                errors.push(format!(
+                    "'{}': invalid environment variable name '{}'",
+                    path, name
+                ));
+            }
+        }
+
+        for condition in &self.requires {
+            if let Err(e) = condition.validate() {
+                errors.push(format!("'{}': {}", path, e));
+            }
+        }
+
+        if self.raw_args && !self.arguments.is_empty() {
+            errors.push(format!(
+                "'{}': a command with raw arguments cannot declare arguments",
+                path
+            ));
+        }
+        if self.raw_args && matches!(self.children, Children::Subcmds(_)) {
+            errors.push(format!(
+                "'{}': a command with raw arguments cannot have subcommands",
+                path
+            ));
+        }
+
+        if let Children::Subcmds(subcommands) = &self.children {
+            for option in self.get_optional_arguments() {
+                errors.extend(validate_global_option(subcommands, &path, &option.name));
+            }
+            errors.extend(validate_names(subcommands, &path));
+            for subcommand in subcommands {
+                errors.extend(subcommand.validate());
+            }
+        }
+        errors
+    }
+
+    /// Get the name of the shell function implementing the command
     pub fn get_mangled_function_name(&self) -> String {
         if self.path.len() == 1 {
             self.path[0].clone()
@@ -172,10 +754,12 @@ impl Command {
         }
     }
 
+    /// Get the command line invoking the command, like `venv init`
     pub fn get_command_path_string(&self) -> String {
         self.path.join(" ")
     }
 
+    /// Get the arguments without a default value
     pub fn get_positional_arguments(&self) -> Vec<&Argument> {
         self.arguments
             .iter()
@@ -183,6 +767,7 @@ impl Command {
             .collect()
     }
 
+    /// Get the options, which have a default value
     pub fn get_optional_arguments(&self) -> Vec<&Argument> {
         self.arguments
             .iter()
@@ -190,12 +775,18 @@ impl Command {
             .collect()
     }
 
+    /// Get the usage line of the help, with escaped colors for printf
     pub fn get_usage_string(&self) -> String {
         let mut args = Vec::new();
 
-        // First, check for subcommands
+        // First, check for subcommands, optional with a default body, after global options
         if matches!(self.children, Children::Subcmds(_)) {
-            args.push("<subcommand>".to_string());
+            args.extend(self.get_option_usages());
+            if self.default_body.is_empty() {
+                args.push("<subcommand>".to_string());
+            } else {
+                args.push("[<subcommand>]".to_string());
+            }
         }
         // Then, add positional arguments
         for arg in &self.arguments {
@@ -212,6 +803,28 @@ impl Command {
             }
         }
         // Finally add optional arguments
+        if matches!(self.children, Children::Body(_)) {
+            args.extend(self.get_option_usages());
+        }
+        if self.raw_args {
+            args.push("[<args> ...]".to_string());
+        } else {
+            args.push("[-h|--help]".to_string());
+        }
+
+        format!(
+            "{COMMAND}{} {OPTIONS}{}{RESET}",
+            self.get_command_path_string(),
+            if args.is_empty() {
+                String::new()
+            } else {
+                args.join(" ")
+            }
+        )
+    }
+
+    fn get_option_usages(&self) -> Vec<String> {
+        let mut usages = Vec::new();
         for arg in &self.arguments {
             if arg.option.is_some() {
                 let flag = if arg.name.len() == 1 {
@@ -221,26 +834,17 @@ impl Command {
                 };
                 if matches!(arg.option, Some(kdl::KdlValue::Bool(_))) {
                     // Boolean flag
-                    args.push(format!("[{}]", flag));
+                    usages.push(format!("[{}]", flag));
                 } else {
                     // String option
-                    args.push(format!("[{} <{}>]", flag, arg.name));
+                    usages.push(format!("[{} <{}>]", flag, arg.name));
                 }
             }
         }
-        args.push("[-h|--help]".to_string());
-
-        format!(
-            "{COMMAND}{} {OPTIONS}{}{RESET}",
-            self.get_command_path_string(),
-            if args.is_empty() {
-                String::new()
-            } else {
-                args.join(" ")
-            }
-        )
+        usages
     }
 
+    /// Get the help text, with escaped colors for printf
     pub fn get_help_string(&self) -> String {
         let mut help_string = String::new();
         if let Some(desc) = &self.description {
@@ -276,9 +880,19 @@ impl Command {
             }
         }
 
+        // Raw arguments, including the help flag, are given to the body
+        if self.raw_args {
+            return help_string;
+        }
+
+        // Options of a command with subcommands are global, given before the subcommand
         let opt_args = self.get_optional_arguments();
-        help_string.push_str(&format!("\n{TITLE}Options:{RESET}\n"));
         let width = self.get_max_width();
+        if matches!(self.children, Children::Subcmds(_)) && !opt_args.is_empty() {
+            help_string.push_str(&format!("\n{TITLE}Global options:{RESET}\n"));
+        } else {
+            help_string.push_str(&format!("\n{TITLE}Options:{RESET}\n"));
+        }
         for arg in opt_args {
             let flag = if arg.name.len() == 1 {
                 format!("-{}", arg.name)
@@ -290,6 +904,11 @@ impl Command {
                 flag, arg.help
             ));
         }
+        if matches!(self.children, Children::Subcmds(_))
+            && !self.get_optional_arguments().is_empty()
+        {
+            help_string.push_str(&format!("\n{TITLE}Options:{RESET}\n"));
+        }
         help_string.push_str(&format!(
             "  {COMMAND}{:width$}{RESET}  Show help information\n",
             "-h, --help"
@@ -298,6 +917,7 @@ impl Command {
         help_string
     }
 
+    /// Get the width of the name column of the help
     pub fn get_max_width(&self) -> usize {
         let mut width = "-h, --help".len();
         if let Children::Subcmds(subcmds) = &self.children {
@@ -316,3 +936,295 @@ impl Command {
         width
     }
 }
+
+/// Check every command generates valid shell code
+pub fn validate(commands: &[Command]) -> Result<(), Error> {
+    let mut errors = validate_names(commands, "");
+    for command in commands {
+        errors.extend(command.validate());
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::msg(format!(
+            "Invalid commands:\n  {}",
+            errors.join("\n  ")
+        )))
+    }
+}
+
+/// Find a command from its path, like `["venv", "init"]`
+pub fn find_command<'a>(commands: &'a [Command], path: &[String]) -> Option<&'a Command> {
+    let (name, rest) = path.split_first()?;
+    let command = commands.iter().find(|c| &c.name == name)?;
+    match (&command.children, rest.is_empty()) {
+        (_, true) => Some(command),
+        (Children::Subcmds(subcommands), false) => find_command(subcommands, rest),
+        (Children::Body(_), false) => None,
+    }
+}
+
+/// Check the descendants of a command do not reuse the variable of one of its global options
+fn validate_global_option(commands: &[Command], parent_path: &str, name: &str) -> Vec<String> {
+    let mut errors = Vec::new();
+    for command in commands {
+        if command.arguments.iter().any(|arg| arg.name == name) {
+            errors.push(format!(
+                "'{}': argument '{}' conflicts with the global option of '{}'",
+                command.get_command_path_string(),
+                name,
+                parent_path
+            ));
+        }
+        if let Children::Subcmds(subcommands) = &command.children {
+            errors.extend(validate_global_option(subcommands, parent_path, name));
+        }
+    }
+    errors
+}
+
+fn validate_names(commands: &[Command], parent_path: &str) -> Vec<String> {
+    let mut errors = Vec::new();
+    for (index, command) in commands.iter().enumerate() {
+        if commands[..index].iter().any(|c| c.name == command.name) {
+            if parent_path.is_empty() {
+                errors.push(format!("command '{}' is defined twice", command.name));
+            } else {
+                errors.push(format!(
+                    "'{}': subcommand '{}' is defined twice",
+                    parent_path, command.name
+                ));
+            }
+        }
+    }
+    errors
+}
+
+/// Remove colors, either escaped for printf as in the help, or already
+/// printed as in the output of the commands, for outputs other than the terminal
+pub fn strip_colors(s: &str) -> String {
+    let mut output = String::new();
+    let mut rest = s;
+    while let Some(start) = ["\\033[", "\x1b["]
+        .iter()
+        .filter_map(|escape| rest.find(escape))
+        .min()
+    {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        rest = rest.find('m').map_or("", |end| &rest[end + 1..]);
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Get the name of a command declared explicitly, as `cmd name args...`
+fn get_cmd_name(node: &KdlNode) -> Option<&str> {
+    if node.name().value() != "cmd" {
+        return None;
+    }
+    match node.entries().first() {
+        Some(entry) if entry.name().is_none() => entry.value().as_string(),
+        _ => None,
+    }
+}
+
+/// Get the name and arguments of a top-level `args "name" ...` node, defining
+/// an argument set that commands add to their own arguments with `use "name"`
+pub(crate) fn parse_argument_set(node: &KdlNode) -> Option<(String, Vec<Argument>)> {
+    if node.name().value() != "args" || node.children().is_some() {
+        return None;
+    }
+    let (first, entries) = node.entries().split_first()?;
+    let name = first
+        .value()
+        .as_string()
+        .filter(|_| first.name().is_none())?;
+    Some((
+        name.to_string(),
+        entries.iter().map(Argument::parse).collect(),
+    ))
+}
+
+/// Get the variables of a top-level `let name="value"...` node, with their
+/// text as value
+pub(crate) fn parse_variables(node: &KdlNode) -> Option<Vec<(&str, String)>> {
+    if node.name().value() != "let" || node.children().is_some() || node.entries().is_empty() {
+        return None;
+    }
+    node.entries()
+        .iter()
+        .map(|entry| {
+            let value = match entry.value() {
+                KdlValue::String(s) => s.clone(),
+                KdlValue::Null => String::new(),
+                value => value.to_string().trim_start_matches('#').to_string(),
+            };
+            Some((entry.name()?.value(), value))
+        })
+        .collect()
+}
+
+/// Get the argument sets of a `use "name"...` node
+fn get_used_sets(node: &KdlNode) -> Option<Vec<&str>> {
+    if node.name().value() != "use" || node.children().is_some() || node.entries().is_empty() {
+        return None;
+    }
+    node.entries()
+        .iter()
+        .map(|entry| entry.value().as_string().filter(|_| entry.name().is_none()))
+        .collect()
+}
+
+/// Get the directory of a `cwd "dir"` node
+fn get_cwd(node: &KdlNode) -> Option<&str> {
+    if node.name().value() != "cwd" || node.children().is_some() {
+        return None;
+    }
+    match node.entries() {
+        [entry] if entry.name().is_none() => entry.value().as_string(),
+        _ => None,
+    }
+}
+
+/// Get the absolute directory of the KDL file, the current one when unknown
+fn get_kdl_dir(dir: Option<&Path>) -> PathBuf {
+    // The parent of a bare file name is empty
+    let dir = dir
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    std::path::absolute(dir).unwrap_or_else(|_| dir.to_path_buf())
+}
+
+/// Check if a node is an `env { NAME "value"... }` block, with the
+/// environment variables of a command
+fn is_env_block(node: &KdlNode) -> bool {
+    node.name().value() == "env"
+        && node.children().is_some()
+        && node.entries().is_empty()
+        && node.ty().is_none()
+}
+
+/// Check if a node is a `run { ... }` block, holding the default body of a
+/// command: with a description or arguments, it is a subcommand named `run`
+fn is_run_block(node: &KdlNode) -> bool {
+    node.name().value() == "run"
+        && node.children().is_some()
+        && node.entries().is_empty()
+        && node.ty().is_none()
+}
+
+/// Get the text of a `script #"..."#` node, taken as shell code byte-for-byte
+fn get_script(node: &KdlNode) -> Option<&str> {
+    if node.name().value() != "script" || node.children().is_some() {
+        return None;
+    }
+    match node.entries() {
+        [entry] if entry.name().is_none() && is_raw_string(entry) => entry.value().as_string(),
+        _ => None,
+    }
+}
+
+fn is_raw_string(entry: &KdlEntry) -> bool {
+    entry
+        .format()
+        .is_some_and(|format| format.value_repr.starts_with('#'))
+}
+
+/// Get the shell text of a body line entry, properties being `key=value`:
+/// - identifiers and numbers as written
+/// - quoted strings in double quotes, where `$` and backquotes still expand
+/// - raw strings verbatim, without quotes
+/// - `#true`, `#false` and `#null` as `true`, `false` and `null`
+///
+/// Strings go through `interpolate` first.
+fn get_shell_word(entry: &KdlEntry, interpolate: &dyn Fn(&str) -> String) -> String {
+    let repr = entry.format().map(|format| format.value_repr.as_str());
+    let value = match (entry.value(), repr) {
+        (KdlValue::String(s), repr) => get_shell_string(&interpolate(s), repr),
+        (KdlValue::Bool(b), _) => b.to_string(),
+        (KdlValue::Null, _) => "null".to_string(),
+        (KdlValue::Float(f), _) if !f.is_finite() => f.to_string().to_lowercase(),
+        (_, Some(repr)) => repr.to_string(),
+        (value, None) => value.to_string(),
+    };
+    match entry.name() {
+        Some(key) => format!(
+            "{}={}",
+            get_shell_string(&interpolate(key.value()), key.repr()),
+            value
+        ),
+        None => value,
+    }
+}
+
+fn get_shell_string(s: &str, repr: Option<&str>) -> String {
+    match repr {
+        Some(repr) if repr.starts_with('#') => s.to_string(),
+        Some(repr) if !repr.starts_with('"') => s.to_string(),
+        Some(_) => quote_double(s),
+        // Strings built without a source are quoted when KDL would quote them
+        None if KdlIdentifier::from(s).to_string() == s => s.to_string(),
+        None => quote_double(s),
+    }
+}
+
+/// Get the parts of a body line entry that the shell line cannot show
+fn get_lossy_parts(entry: &KdlEntry) -> Vec<String> {
+    let mut messages = Vec::new();
+    let shown = match entry.name() {
+        Some(key) => format!("property '{}'", key.value()),
+        None => format!("'{}'", entry.value()),
+    };
+    if let Some(ty) = entry.ty() {
+        messages.push(format!(
+            "type annotation '({})' of {} is ignored",
+            ty.value(),
+            shown
+        ));
+    }
+    let lossy = match entry.value() {
+        KdlValue::Null => true,
+        KdlValue::Float(f) => !f.is_finite(),
+        _ => false,
+    };
+    if lossy {
+        messages.push(format!(
+            "{} has no shell equivalent, written as '{}'",
+            shown,
+            get_shell_word(entry, &str::to_string)
+        ));
+    }
+    messages
+}
+
+pub(crate) fn quote_double(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Check a name can be typed in the shell and used in a function name
+pub(crate) fn is_command_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('-')
+        && !name
+            .chars()
+            .any(|c| c.is_whitespace() || "\"'`$;&|<>()[]{}*?!#~=\\".contains(c))
+}
+
+/// Check a name can be used as `{{name}}`, dashes included
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn is_shell_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -0,0 +1,112 @@
+
+# Project commands: load the nearest .eashy.kdl (or eashy.kdl at a git root)
+_EASHY_BIN=__EASHY_BIN__
+_EASHY_PROJECT_CACHE="$HOME/.eashy/cache/projects"
+_EASHY_PROJECT_ALLOWED="$HOME/.eashy/allowed"
+_EASHY_PROJECT_FILE="${_EASHY_PROJECT_FILE:-}"
+_EASHY_PROJECT_FUNCS="${_EASHY_PROJECT_FUNCS:-}"
+_EASHY_PROJECT_SAVED="${_EASHY_PROJECT_SAVED:-}"
+
+# Print the code defining a function and its completion again, if it exists
+_eashy_save_function() {
+    typeset -f "$1" 2>/dev/null || return 0
+    if [ -n "${ZSH_VERSION:-}" ]; then
+        eval '[ -n "${_comps[$1]:-}" ] && print -r -- "compdef ${_comps[$1]} $1"'
+    else
+        complete -p "$1" 2>/dev/null
+    fi
+    return 0
+}
+
+_eashy_project_find() {
+    local dir
+    dir="$(pwd -P)"
+    while [ -n "$dir" ]; do
+        if [ -f "$dir/.eashy.kdl" ]; then
+            echo "$dir/.eashy.kdl"
+            return 0
+        fi
+        if [ -e "$dir/.git" ] && [ -f "$dir/eashy.kdl" ]; then
+            echo "$dir/eashy.kdl"
+            return 0
+        fi
+        dir="${dir%/*}"
+    done
+    return 1
+}
+
+_eashy_project_unload() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    local name
+    for name in $_EASHY_PROJECT_FUNCS; do
+        unset -f "$name"
+        if [ -n "${ZSH_VERSION:-}" ]; then
+            compdef -d "$name" 2>/dev/null
+        else
+            complete -r "$name" 2>/dev/null
+        fi
+    done
+    # Put back the functions the project replaced
+    eval "$_EASHY_PROJECT_SAVED"
+    _EASHY_PROJECT_FILE=""
+    _EASHY_PROJECT_FUNCS=""
+    _EASHY_PROJECT_SAVED=""
+}
+
+# Check the file was allowed by `eashy allow` in its current content
+_eashy_project_allowed() {
+    local sum
+    sum="$(cksum < "$1" | awk '{ print $1, $2 }')"
+    [ -f "$_EASHY_PROJECT_ALLOWED" ] && grep -Fqx -- "$sum $1" "$_EASHY_PROJECT_ALLOWED"
+}
+
+_eashy_project_load() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    local file="$1" cache name
+    if ! _eashy_project_allowed "$file"; then
+        printf 'eashy: %s is not allowed, run `eashy allow` to load it\n' "$file" >&2
+        return 1
+    fi
+    cache="$_EASHY_PROJECT_CACHE/$(printf '%s' "$file" | cksum | cut -d ' ' -f 1).sh"
+    if [ ! -f "$cache" ] || [ "$file" -nt "$cache" ]; then
+        mkdir -p "$_EASHY_PROJECT_CACHE"
+        "$_EASHY_BIN" --quiet --file "$file" --output "$cache" || return 1
+    fi
+    # Save the functions defined by the project script before it replaces them
+    for name in $(sed -n 's/^\([^ (]*\)() {$/\1/p' "$cache"); do
+        _EASHY_PROJECT_SAVED="$_EASHY_PROJECT_SAVED
+$(_eashy_save_function "$name")"
+        _EASHY_PROJECT_FUNCS="$_EASHY_PROJECT_FUNCS $name"
+    done
+    . "$cache"
+    _EASHY_PROJECT_FILE="$file"
+}
+
+_eashy_project_hook() {
+    local file
+    file="$(_eashy_project_find)"
+    if [ "$file" = "$_EASHY_PROJECT_FILE" ]; then
+        return
+    fi
+    _eashy_project_unload
+    if [ -n "$file" ]; then
+        _eashy_project_load "$file"
+    fi
+}
+
+if [ -n "${ZSH_VERSION:-}" ]; then
+    autoload -Uz add-zsh-hook
+    add-zsh-hook chpwd _eashy_project_hook
+elif [ -n "${BASH_VERSION:-}" ]; then
+    _eashy_project_prompt() {
+        if [ "$PWD" != "${_EASHY_PROJECT_PWD:-}" ]; then
+            _EASHY_PROJECT_PWD="$PWD"
+            _eashy_project_hook
+        fi
+    }
+    case ";${PROMPT_COMMAND:-};" in
+        *";_eashy_project_prompt;"*) ;;
+        *) PROMPT_COMMAND="_eashy_project_prompt${PROMPT_COMMAND:+;$PROMPT_COMMAND}" ;;
+    esac
+fi
+_eashy_project_hook
@@ -0,0 +1,38 @@
+
+# Auto reload: regenerate and source this script again when its KDL sources change
+_EASHY_RELOAD_SCRIPT=__SCRIPT__
+
+_eashy_reload_changed() {
+    [ "$1" -nt "$_EASHY_RELOAD_SCRIPT" ] || return 1
+    set -- "$2" $(cksum < "$1")
+    if [ "$1" != "$2" ]; then
+        return 0
+    fi
+    _EASHY_RELOAD_TOUCH=1
+    return 1
+}
+
+_eashy_reload_hook() {
+    _EASHY_RELOAD_TOUCH=""
+    if __CHANGED__; then
+        if __EASHY__; then
+            . "$_EASHY_RELOAD_SCRIPT"
+            return
+        fi
+        _EASHY_RELOAD_TOUCH=1
+    fi
+    # Sources only touched, or failing to parse: wait for their next change
+    if [ -n "$_EASHY_RELOAD_TOUCH" ]; then
+        touch "$_EASHY_RELOAD_SCRIPT"
+    fi
+}
+
+if [ -n "${ZSH_VERSION:-}" ]; then
+    autoload -Uz add-zsh-hook
+    add-zsh-hook precmd _eashy_reload_hook
+elif [ -n "${BASH_VERSION:-}" ]; then
+    case ";${PROMPT_COMMAND:-};" in
+        *";_eashy_reload_hook;"*) ;;
+        *) PROMPT_COMMAND="_eashy_reload_hook${PROMPT_COMMAND:+;$PROMPT_COMMAND}" ;;
+    esac
+fi
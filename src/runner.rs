@@ -0,0 +1,141 @@
+use anyhow::Error;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use eashy::parser;
+use eashy::{Children, Command, Example, Target};
+
+/// Shells the examples run with when none is given
+const SHELLS: [&str; 2] = ["bash", "zsh"];
+
+/// Run the examples of every command in a real shell, sourcing the generated
+/// script from a temporary directory
+pub fn run(commands: &[Command], shells: &[String], quiet: bool) -> Result<(), Error> {
+    let shells: Vec<String> = if shells.is_empty() {
+        SHELLS
+            .iter()
+            .filter(|shell| is_installed(shell))
+            .map(|shell| shell.to_string())
+            .collect()
+    } else {
+        shells.to_vec()
+    };
+    if shells.is_empty() {
+        return Err(Error::msg(
+            "No shell found to run the examples, install bash or zsh",
+        ));
+    }
+
+    let mut examples = Vec::new();
+    collect_examples(commands, &mut examples);
+    if examples.is_empty() {
+        if !quiet {
+            println!("ℹ️  No examples found, add some with `example` nodes");
+        }
+        return Ok(());
+    }
+
+    let dir = std::env::temp_dir().join(format!("eashy-test-{}", process::id()));
+    fs::create_dir_all(&dir)
+        .map_err(|e| Error::msg(format!("Failed to create '{}': {}", dir.display(), e)))?;
+    let result = eashy::generate(commands, Target::Shell)
+        .and_then(|script| {
+            fs::write(dir.join("eashy.sh"), script)
+                .map_err(|e| Error::msg(format!("Failed to write the test script: {}", e)))
+        })
+        .and_then(|()| run_examples(&dir, &shells, &examples, quiet));
+    let _ = fs::remove_dir_all(&dir);
+
+    let (passed, failed) = result?;
+    if !quiet || failed > 0 {
+        println!();
+        println!("{} passed, {} failed", passed, failed);
+    }
+    if failed > 0 {
+        return Err(Error::msg(format!("{} example(s) failed", failed)));
+    }
+    Ok(())
+}
+
+fn collect_examples<'a>(commands: &'a [Command], examples: &mut Vec<(&'a Command, &'a Example)>) {
+    for command in commands {
+        examples.extend(command.examples.iter().map(|example| (command, example)));
+        if let Children::Subcmds(subcommands) = &command.children {
+            collect_examples(subcommands, examples);
+        }
+    }
+}
+
+/// Run every example in every shell, returning the number of passed and failed ones
+fn run_examples(
+    dir: &Path,
+    shells: &[String],
+    examples: &[(&Command, &Example)],
+    quiet: bool,
+) -> Result<(usize, usize), Error> {
+    let (mut passed, mut failed) = (0, 0);
+
+    for shell in shells {
+        for (command, example) in examples {
+            let invocation = if example.args.is_empty() {
+                command.get_command_path_string()
+            } else {
+                format!("{} {}", command.get_command_path_string(), example.args)
+            };
+            let output = process::Command::new(shell)
+                .arg("-c")
+                .arg(format!(". ./eashy.sh\n{}", invocation))
+                .current_dir(dir)
+                .output()
+                .map_err(|e| Error::msg(format!("Failed to run {}: {}", shell, e)))?;
+
+            let status = output.status.code().unwrap_or(-1);
+            let stdout = parser::strip_colors(&String::from_utf8_lossy(&output.stdout));
+            let stderr = parser::strip_colors(&String::from_utf8_lossy(&output.stderr));
+
+            let mut failures = Vec::new();
+            if status != example.status {
+                failures.push(format!(
+                    "exit status is {}, expected {}",
+                    status, example.status
+                ));
+            }
+            if let Some(expected) = &example.stdout
+                && !stdout.contains(expected.as_str())
+            {
+                failures.push(format!("stdout does not contain \"{}\"", expected));
+            }
+            if let Some(expected) = &example.stderr
+                && !stderr.contains(expected.as_str())
+            {
+                failures.push(format!("stderr does not contain \"{}\"", expected));
+            }
+
+            if failures.is_empty() {
+                passed += 1;
+                if !quiet {
+                    println!("✅ [{}] {}", shell, invocation);
+                }
+            } else {
+                failed += 1;
+                println!("❌ [{}] {}", shell, invocation);
+                for failure in failures {
+                    println!("   {}", failure);
+                }
+                for line in stderr.lines() {
+                    println!("   stderr: {}", line);
+                }
+            }
+        }
+    }
+
+    Ok((passed, failed))
+}
+
+fn is_installed(shell: &str) -> bool {
+    process::Command::new(shell)
+        .args(["-c", "true"])
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
@@ -1,20 +1,53 @@
+//! Shell script generation, for bash and zsh
+
+use std::path::{Path, PathBuf};
+
 use kdl::KdlValue;
 
-use crate::parser::{ArgPrefix, Argument, Children, CmdPrefix, Command, ERROR, RESET, TITLE};
+use crate::conditions::Condition;
+use crate::parser::{
+    ArgPrefix, Argument, Children, CmdPrefix, Command, ERROR, Hooks, RESET, TITLE, quote_double,
+};
+
+/// A KDL file a script is generated from, recorded in the script header
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    /// Absolute path of the file
+    pub path: PathBuf,
+    /// Checksum of the file content, as computed by the POSIX `cksum` utility
+    pub checksum: u32,
+}
+
+impl SourceFile {
+    /// Record a file, with an absolute path so the script can check it from any directory
+    pub fn new(path: &Path, content: &str) -> Self {
+        SourceFile {
+            path: std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()),
+            checksum: cksum(content.as_bytes()),
+        }
+    }
+}
 
 /// Generate shell script from commands
-pub fn generate_script(commands: &[Command]) -> String {
+pub fn generate_script(commands: &[Command], sources: &[SourceFile]) -> String {
     let mut output = String::new();
     output.push_str(
         r#"
 # Auto-generated CLI shell functions
 # All modifications will be lost when terminal is reloaded
-
 "#,
     );
+    for source in sources {
+        output.push_str(&format!(
+            "# Source: {} (cksum {})\n",
+            source.path.display(),
+            source.checksum
+        ));
+    }
+    output.push('\n');
 
     for command in commands {
-        output.push_str(&generate_function(command));
+        output.push_str(&generate_function(command, &Scope::default()));
         output.push_str(&generate_autocompletion(command));
     }
 
@@ -22,8 +55,31 @@ pub fn generate_script(commands: &[Command]) -> String {
     output
 }
 
-fn generate_function(command: &Command) -> String {
+/// Settings of a command, completed with the ones of its parents
+#[derive(Default)]
+struct Scope {
+    hooks: Hooks,
+    cwd: Option<String>,
+    env: Vec<(String, String)>,
+    affects_caller: bool,
+}
+
+impl Scope {
+    fn inherit(&self, command: &Command) -> Scope {
+        Scope {
+            hooks: command.hooks.inherit(&self.hooks),
+            cwd: command.cwd.clone().or_else(|| self.cwd.clone()),
+            env: [self.env.as_slice(), &command.env].concat(),
+            affects_caller: self.affects_caller || command.affects_caller,
+        }
+    }
+}
+
+/// Generate the function of a command and of its subcommands, with the
+/// settings of their parents
+fn generate_function(command: &Command, parent: &Scope) -> String {
     let mut output = String::new();
+    let scope = parent.inherit(command);
     let function_name = command.get_mangled_function_name();
 
     output.push_str(&format!("{}() {{\n", function_name));
@@ -33,29 +89,65 @@ fn generate_function(command: &Command) -> String {
         output.push_str("    setopt localoptions sh_word_split 2>/dev/null || true\n");
     }
 
-    match &command.children {
+    let command_lines = match &command.children {
         Children::Subcmds(subcommands) => {
-            generate_subcommand_func_body(&mut output, command, subcommands);
+            generate_subcommand_func_body(&mut output, command, subcommands, &scope);
+            &command.default_body
         }
         Children::Body(command_lines) => {
-            generate_leaf_func_body(&mut output, command, command_lines);
+            generate_leaf_func_body(&mut output, command, command_lines, &scope);
+            command_lines
         }
-    }
+    };
 
     output.push_str("}\n\n");
 
+    // With hooks, the body has its own function, returning early without skipping them
+    if !scope.hooks.is_empty() && !command_lines.is_empty() {
+        output.push_str(&format!("{}() {{\n", get_body_function_name(command)));
+        generate_body_lines(&mut output, command, command_lines, "    ");
+        output.push_str("}\n\n");
+    }
+
     if let Children::Subcmds(subcommands) = &command.children {
         for subcommand in subcommands {
-            output.push_str(&generate_function(subcommand));
+            output.push_str(&generate_function(subcommand, &scope));
         }
     }
 
     output
 }
 
-fn generate_subcommand_func_body(output: &mut String, command: &Command, subcommands: &[Command]) {
+fn generate_subcommand_func_body(
+    output: &mut String,
+    command: &Command,
+    subcommands: &[Command],
+    scope: &Scope,
+) {
+    // Global options, before the subcommand, are visible in its body
+    let optional_args = command.get_optional_arguments();
+    if !optional_args.is_empty() {
+        generate_option_defaults(output, &optional_args);
+        output.push_str("    while [ $# -gt 0 ]; do\n");
+        output.push_str("        case \"$1\" in\n");
+        generate_option_cases(output, command, &optional_args);
+        output.push_str("            *)\n");
+        output.push_str("                break\n");
+        output.push_str("                ;;\n");
+        output.push_str("        esac\n");
+        output.push_str("    done\n\n");
+    }
+
     output.push_str("    subcmd=\"$1\"\n");
     output.push_str("    if [ $# -gt 0 ]; then shift; fi\n");
+
+    // Check the requirements of the subcommands too, the help being always available
+    if !command.requires.is_empty() {
+        output
+            .push_str("    if [ \"$subcmd\" != \"-h\" ] && [ \"$subcmd\" != \"--help\" ]; then\n");
+        generate_requirement_checks(output, command, "        ");
+        output.push_str("    fi\n");
+    }
     output.push_str("    case \"$subcmd\" in\n");
 
     // Handle help flags as special subcommands
@@ -67,6 +159,19 @@ fn generate_subcommand_func_body(output: &mut String, command: &Command, subcomm
     output.push_str("            return\n");
     output.push_str("            ;;\n");
 
+    // Run the default body without a subcommand
+    if !command.default_body.is_empty() {
+        output.push_str("        \"\")\n");
+        generate_execution(
+            output,
+            command,
+            &command.default_body,
+            scope,
+            "            ",
+        );
+        output.push_str("            ;;\n");
+    }
+
     // Handle regular subcommands
     for subcommand in subcommands {
         output.push_str(&format!(
@@ -98,15 +203,27 @@ fn generate_subcommand_func_body(output: &mut String, command: &Command, subcomm
     output.push_str("    esac\n");
 }
 
-fn generate_leaf_func_body(output: &mut String, command: &Command, command_lines: &[String]) {
+fn generate_leaf_func_body(
+    output: &mut String,
+    command: &Command,
+    command_lines: &[String],
+    scope: &Scope,
+) {
+    // Raw arguments are left in "$@" for the body
+    if command.raw_args {
+        generate_requirement_checks(output, command, "    ");
+        output.push_str("    # Execute command\n");
+        generate_execution(output, command, command_lines, scope, "    ");
+        return;
+    }
+
     let positional_args = command.get_positional_arguments();
     let optional_args = command.get_optional_arguments();
 
     // Initialize variables with defaults
     for arg in &command.arguments {
-        if let Some(option) = &arg.option {
-            let default_value = format_default_value(option);
-            output.push_str(&format!("    {}={}\n", arg.name, default_value));
+        if arg.option.is_some() {
+            generate_option_defaults(output, &[arg]);
         } else {
             // All positional arguments default to empty strings
             output.push_str(&format!("    {}=\"\"\n", arg.name));
@@ -132,7 +249,58 @@ fn generate_leaf_func_body(output: &mut String, command: &Command, command_lines
     output.push_str("                ;;\n");
 
     // Handle optional arguments
-    for arg in &optional_args {
+    generate_option_cases(output, command, &optional_args);
+
+    // Handle unknown options
+    output.push_str("            -*)\n");
+    output.push_str(&generate_error_message(
+        "Unknown option: $1",
+        command,
+        "                ",
+    ));
+    output.push_str("                return 1\n");
+    output.push_str("                ;;\n");
+
+    // Handle positional arguments
+    output.push_str("            *)\n");
+    if positional_args.is_empty() {
+        output.push_str(&generate_error_message(
+            "Too many arguments",
+            command,
+            "                ",
+        ));
+        output.push_str("                return 1\n");
+    } else {
+        generate_positional_parsing(output, &positional_args, command);
+    }
+    output.push_str("                ;;\n");
+
+    output.push_str("        esac\n");
+    output.push_str("    done\n\n");
+
+    // Validate required positional arguments
+    generate_positional_validation(output, &positional_args, command);
+
+    generate_requirement_checks(output, command, "    ");
+
+    // Generate command execution
+    output.push_str("    # Execute command\n");
+    generate_execution(output, command, command_lines, scope, "    ");
+}
+
+/// Initialize option variables with their default value
+fn generate_option_defaults(output: &mut String, optional_args: &[&Argument]) {
+    for arg in optional_args {
+        if let Some(option) = &arg.option {
+            let default_value = format_default_value(option);
+            output.push_str(&format!("    {}={}\n", arg.name, default_value));
+        }
+    }
+}
+
+/// Write the cases parsing options, in a `case "$1"` of a loop on the arguments
+fn generate_option_cases(output: &mut String, command: &Command, optional_args: &[&Argument]) {
+    for arg in optional_args {
         let flag = if arg.name.len() == 1 {
             format!("-{}", arg.name)
         } else {
@@ -160,47 +328,145 @@ fn generate_leaf_func_body(output: &mut String, command: &Command, command_lines
             output.push_str("                ;;\n");
         }
     }
+}
 
-    // Handle unknown options
-    output.push_str("            -*)\n");
-    output.push_str(&generate_error_message(
-        "Unknown option: $1",
-        command,
-        "                ",
-    ));
-    output.push_str("                return 1\n");
-    output.push_str("                ;;\n");
+/// Check the conditions of a command met at runtime, failing otherwise
+fn generate_requirement_checks(output: &mut String, command: &Command, indent: &str) {
+    for condition in &command.requires {
+        let error = |indent: &str| {
+            format!(
+                "{indent}printf \"{ERROR}Error:{RESET} '{}' requires {}\\n\" >&2\n{indent}return 1\n",
+                command.get_command_path_string(),
+                escape_printf(&condition.to_string())
+            )
+        };
+        let (value, pattern) = match condition {
+            Condition::Has(program) => {
+                output.push_str(&format!(
+                    "{indent}if ! command -v {} >/dev/null 2>&1; then\n",
+                    quote_shell(program)
+                ));
+                output.push_str(&error(&format!("{indent}    ")));
+                output.push_str(&format!("{indent}fi\n"));
+                continue;
+            }
+            Condition::Os(os) => ("$(uname -s)", Condition::get_uname_pattern(os)),
+            Condition::Host(pattern) => ("$(uname -n)", pattern.as_str()),
+        };
+        output.push_str(&format!("{indent}case \"{}\" in\n", value));
+        output.push_str(&format!("{indent}    {}) ;;\n", pattern));
+        output.push_str(&format!("{indent}    *)\n"));
+        output.push_str(&error(&format!("{indent}        ")));
+        output.push_str(&format!("{indent}        ;;\n"));
+        output.push_str(&format!("{indent}esac\n"));
+    }
+}
 
-    // Handle positional arguments
-    output.push_str("            *)\n");
-    if positional_args.is_empty() {
-        output.push_str(&generate_error_message(
-            "Too many arguments",
-            command,
-            "                ",
+/// Write body lines, between the hooks of the command and of its parents, in
+/// a subshell with their working directory and environment variables
+///
+/// A failing `before` or `after` line stops the execution, then the
+/// `on-error` and `finally` lines run, and the function returns the status of
+/// the failing line.
+fn generate_execution(
+    output: &mut String,
+    command: &Command,
+    lines: &[String],
+    scope: &Scope,
+    indent: &str,
+) {
+    // Keep the directory and environment of the caller, unless asked otherwise
+    let in_subshell = (scope.cwd.is_some() || !scope.env.is_empty()) && !scope.affects_caller;
+    let outer_indent = indent;
+    let indent = if in_subshell {
+        output.push_str(&format!("{}(\n", outer_indent));
+        format!("{}    ", outer_indent)
+    } else {
+        outer_indent.to_string()
+    };
+    let indent = indent.as_str();
+    match scope.cwd.as_deref() {
+        Some("git-root") => output.push_str(&format!(
+            "{}_cwd=$(git rev-parse --show-toplevel) && cd \"$_cwd\" || return $?\n",
+            indent
+        )),
+        Some(dir) => output.push_str(&format!("{}cd {} || return $?\n", indent, quote_dir(dir))),
+        None => {}
+    }
+    for (name, value) in &scope.env {
+        output.push_str(&format!(
+            "{}export {}={}\n",
+            indent,
+            name,
+            quote_double(value)
         ));
-        output.push_str("                return 1\n");
+    }
+
+    let hooks = &scope.hooks;
+    if hooks.is_empty() {
+        generate_body_lines(output, command, lines, indent);
     } else {
-        generate_positional_parsing(output, &positional_args, command);
+        let stop = " || { _status=$?; break; }";
+        let inner_indent = format!("{}    ", indent);
+        output.push_str(&format!("{}local _status=0\n", indent));
+        output.push_str(&format!("{}while :; do\n", indent));
+        generate_lines(output, &hooks.before, stop, &inner_indent);
+        if !lines.is_empty() {
+            // Commands with raw arguments give them to their body
+            let args = if command.raw_args { " \"$@\"" } else { "" };
+            output.push_str(&format!(
+                "{}{}{}{}\n",
+                inner_indent,
+                get_body_function_name(command),
+                args,
+                stop
+            ));
+        }
+        generate_lines(output, &hooks.after, stop, &inner_indent);
+        output.push_str(&format!("{}break\n", inner_indent));
+        output.push_str(&format!("{}done\n", indent));
+        if !hooks.on_error.is_empty() {
+            output.push_str(&format!("{}if [ $_status -ne 0 ]; then\n", indent));
+            generate_lines(output, &hooks.on_error, "", &inner_indent);
+            output.push_str(&format!("{}fi\n", indent));
+        }
+        generate_lines(output, &hooks.finally, "", indent);
+        output.push_str(&format!("{}return $_status\n", indent));
     }
-    output.push_str("                ;;\n");
 
-    output.push_str("        esac\n");
-    output.push_str("    done\n\n");
+    if in_subshell {
+        output.push_str(&format!("{})\n", outer_indent));
+    }
+}
 
-    // Validate required positional arguments
-    generate_positional_validation(output, &positional_args, command);
+/// Get the name of the function running the body of a command with hooks
+fn get_body_function_name(command: &Command) -> String {
+    format!("_{}__body_", command.path.join("_"))
+}
 
-    // Generate command execution
-    output.push_str("    # Execute command\n");
-    for cmd_line in command_lines {
-        let mut return_early_code = String::new();
-        if command.prefix == CmdPrefix::UntilError {
-            return_early_code += " || return $?";
-        } else if command.prefix == CmdPrefix::UntilSuccess {
-            return_early_code += " && return 0";
+/// Write body lines, chained according to the command prefix
+fn generate_body_lines(output: &mut String, command: &Command, lines: &[String], indent: &str) {
+    let return_early_code = match command.prefix {
+        CmdPrefix::UntilError => " || return $?",
+        CmdPrefix::UntilSuccess => " && return 0",
+        CmdPrefix::None => "",
+    };
+    generate_lines(output, lines, return_early_code, indent);
+}
+
+/// Write shell lines, each one followed by the same code
+fn generate_lines(output: &mut String, lines: &[String], suffix: &str, indent: &str) {
+    for cmd_line in lines {
+        if cmd_line.contains('\n') {
+            // Scripts are not indented, to keep heredocs and multi-line strings as written
+            if suffix.is_empty() {
+                output.push_str(&format!("{}\n", cmd_line));
+            } else {
+                output.push_str(&format!("{indent}{{\n{}\n{indent}}}{}\n", cmd_line, suffix));
+            }
+        } else {
+            output.push_str(&format!("{}{}{}\n", indent, cmd_line, suffix));
         }
-        output.push_str(&format!("    {}{}\n", cmd_line, return_early_code));
     }
 }
 
@@ -371,6 +637,8 @@ fn format_default_value(value: &KdlValue) -> String {
     match value {
         KdlValue::Bool(val) => val.to_string(),
         KdlValue::Null => "\"\"".to_string(),
+        // Display would quote strings that are not plain identifiers again
+        KdlValue::String(val) => format!("\"{}\"", val.replace('"', "\\\"")),
         _ => format!("\"{}\"", value),
     }
 }
@@ -473,6 +741,100 @@ fn generate_script_end(commands: &[Command]) -> String {
     output
 }
 
+/// Generate the hook loading project commands when changing directory
+///
+/// The hook runs `eashy_bin` to generate the script of each project.
+pub fn generate_project_hook(eashy_bin: &Path) -> String {
+    include_str!("project.sh").replace("__EASHY_BIN__", &quote_path(eashy_bin))
+}
+
+/// Generate the prompt hook regenerating and sourcing the script again when
+/// one of its sources changed
+///
+/// `input` is the file or directory the script is generated from, and `args`
+/// the eashy arguments regenerating it.
+pub fn generate_reload_hook(
+    eashy_bin: &Path,
+    args: &[String],
+    script: &Path,
+    input: &Path,
+    sources: &[SourceFile],
+) -> String {
+    let mut checks: Vec<String> = sources
+        .iter()
+        .map(|source| {
+            format!(
+                "_eashy_reload_changed {} {}",
+                quote_path(&source.path),
+                source.checksum
+            )
+        })
+        .collect();
+    // Files added to or removed from a directory input
+    if input.is_dir() {
+        checks.push(format!(
+            "[ {} -nt \"$_EASHY_RELOAD_SCRIPT\" ]",
+            quote_path(input)
+        ));
+    }
+    if checks.is_empty() {
+        checks.push("false".to_string());
+    }
+    let command = std::iter::once(quote_path(eashy_bin))
+        .chain(args.iter().map(|arg| quote_shell(arg)))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    include_str!("reload.sh")
+        .replace("__SCRIPT__", &quote_path(script))
+        .replace("__CHANGED__", &checks.join(" || "))
+        .replace("__EASHY__", &command)
+}
+
+/// Quote a string for the shell, using single quotes
+pub(crate) fn quote_shell(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// Quote a working directory, expanding a leading `~` and the variables of
+/// directories starting with `$`
+fn quote_dir(dir: &str) -> String {
+    match dir.strip_prefix('~') {
+        Some("") => "\"$HOME\"".to_string(),
+        Some(rest) if rest.starts_with('/') => format!("\"$HOME\"{}", quote_shell(rest)),
+        _ if dir.starts_with('$') => quote_double(dir),
+        _ => quote_shell(dir),
+    }
+}
+
+fn quote_path(path: &Path) -> String {
+    quote_shell(&path.display().to_string())
+}
+
+/// Compute the CRC of the POSIX `cksum` utility, so that the shell can compare it
+fn cksum(data: &[u8]) -> u32 {
+    fn update(crc: u32, byte: u8) -> u32 {
+        let mut crc = crc ^ ((byte as u32) << 24);
+        for _ in 0..8 {
+            crc = if crc & 0x8000_0000 != 0 {
+                (crc << 1) ^ 0x04C1_1DB7
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    }
+
+    let mut crc = data.iter().fold(0, |crc, &byte| update(crc, byte));
+    // The length is appended to the data, least significant byte first
+    let mut len = data.len();
+    while len > 0 {
+        crc = update(crc, (len & 0xff) as u8);
+        len >>= 8;
+    }
+    !crc
+}
+
 fn generate_error_message(error_msg: &str, command: &Command, indent: &str) -> String {
     let mut output = String::new();
 
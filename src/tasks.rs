@@ -0,0 +1,262 @@
+//! Subcommands imported from the tasks of a Makefile, justfile or package.json
+//!
+//! A command with a `from-make`, `from-just` or `from-npm` child gets a
+//! subcommand per task, running it with the task runner from the directory of
+//! the task file:
+//!
+//! ```kdl
+//! ("Project tasks") \
+//! proj {
+//!     from-make "Makefile"
+//! }
+//! ```
+
+use anyhow::Error;
+use kdl::{KdlNode, KdlValue};
+use std::fs;
+use std::path::Path;
+
+use crate::parser::{self, Command};
+use crate::shell_generator::quote_shell;
+
+/// Tool running the tasks of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TaskRunner {
+    Make,
+    Just,
+    Npm,
+}
+
+/// A task found in a task file, with its description
+struct Task {
+    name: String,
+    description: Option<String>,
+}
+
+impl TaskRunner {
+    /// Get the runner of a `from-*` node
+    pub fn from_directive(node: &KdlNode) -> Option<Self> {
+        if node.children().is_some() {
+            return None;
+        }
+        match node.name().value() {
+            "from-make" => Some(TaskRunner::Make),
+            "from-just" => Some(TaskRunner::Just),
+            "from-npm" => Some(TaskRunner::Npm),
+            _ => None,
+        }
+    }
+
+    fn get_default_file(&self) -> &'static str {
+        match self {
+            TaskRunner::Make => "Makefile",
+            TaskRunner::Just => "justfile",
+            TaskRunner::Npm => "package.json",
+        }
+    }
+
+    fn get_tool(&self) -> &'static str {
+        match self {
+            TaskRunner::Make => "make",
+            TaskRunner::Just => "just",
+            TaskRunner::Npm => "npm",
+        }
+    }
+
+    /// Get the shell line running a task, with the extra arguments as is
+    fn get_command_line(&self, file: &Path, task: &str) -> String {
+        let dir = quote_shell(&file.parent().unwrap_or(file).display().to_string());
+        let file = quote_shell(&file.display().to_string());
+        let task = quote_shell(task);
+        match self {
+            TaskRunner::Make => format!("make -C {} -f {} {} \"$@\"", dir, file, task),
+            TaskRunner::Just => format!(
+                "just --justfile {} --working-directory {} {} \"$@\"",
+                file, dir, task
+            ),
+            TaskRunner::Npm => format!("npm --prefix {} run {} -- \"$@\"", dir, task),
+        }
+    }
+}
+
+/// Build a subcommand per task of the file of a `from-*` node, relative to
+/// `dir` or the current directory
+pub(crate) fn import(
+    runner: TaskRunner,
+    node: &KdlNode,
+    parent_path: &[String],
+    dir: Option<&Path>,
+) -> Result<Vec<Command>, Error> {
+    let file = match node.entries() {
+        [] => runner.get_default_file().to_string(),
+        [entry] if entry.name().is_none() => match entry.value() {
+            KdlValue::String(file) => file.clone(),
+            _ => return Err(Error::msg(format!("invalid {} file", runner.get_tool()))),
+        },
+        _ => {
+            return Err(Error::msg(format!(
+                "'{}' takes a single file path",
+                node.name().value()
+            )));
+        }
+    };
+    let file = dir.map_or_else(|| Path::new(&file).to_path_buf(), |dir| dir.join(&file));
+    let file = std::path::absolute(&file).unwrap_or(file);
+
+    let content = fs::read_to_string(&file)
+        .map_err(|e| Error::msg(format!("failed to read '{}': {}", file.display(), e)))?;
+    let tasks = match runner {
+        TaskRunner::Make => parse_makefile(&content),
+        TaskRunner::Just => parse_justfile(&content),
+        TaskRunner::Npm => parse_package_json(&content)
+            .map_err(|e| Error::msg(format!("invalid '{}': {}", file.display(), e)))?,
+    };
+
+    let mut commands: Vec<Command> = Vec::new();
+    for task in tasks {
+        if !parser::is_command_name(&task.name)
+            || commands.iter().any(|command| command.name == task.name)
+        {
+            continue;
+        }
+        let mut builder = Command::builder(&task.name)
+            .raw_args(true)
+            .line(runner.get_command_line(&file, &task.name));
+        if let Some(description) = task.description {
+            builder = builder.description(description);
+        }
+        let mut command = builder.build()?;
+        command.set_parent_path(parent_path);
+        commands.push(command);
+    }
+    Ok(commands)
+}
+
+/// Find the targets of a Makefile, described by a `## text` comment after
+/// them or by the comment lines above them
+fn parse_makefile(content: &str) -> Vec<Task> {
+    let mut tasks = Vec::new();
+    let mut comments: Vec<&str> = Vec::new();
+
+    for line in content.lines() {
+        if let Some(comment) = line.strip_prefix('#') {
+            comments.push(comment.trim_start_matches('#').trim());
+            continue;
+        }
+        let description = (!comments.is_empty()).then(|| comments.join(" "));
+        comments.clear();
+        if line.starts_with([' ', '\t']) {
+            continue;
+        }
+
+        let Some((targets, rest)) = line.split_once(':') else {
+            continue;
+        };
+        // Skip variable assignments, like `A := b` or `A = b:c`
+        if rest.starts_with('=') || rest.starts_with(":=") || targets.contains(['=', '$', '%']) {
+            continue;
+        }
+        let description = match rest.split_once("##") {
+            Some((_, inline)) => Some(inline.trim().to_string()),
+            None => description,
+        };
+        for target in targets.split_whitespace() {
+            // Special targets, like `.PHONY`
+            if !target.starts_with('.') {
+                tasks.push(Task {
+                    name: target.to_string(),
+                    description: description.clone(),
+                });
+            }
+        }
+    }
+    tasks
+}
+
+/// Find the public recipes of a justfile, described by a `[doc("text")]`
+/// attribute or by the comment lines above them
+fn parse_justfile(content: &str) -> Vec<Task> {
+    let mut tasks = Vec::new();
+    let mut comments: Vec<&str> = Vec::new();
+    let mut doc = None;
+    let mut private = false;
+
+    for line in content.lines() {
+        if line.starts_with("#!") || line.starts_with([' ', '\t']) {
+            comments.clear();
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            comments.push(comment.trim());
+            continue;
+        }
+        if let Some(attributes) = line.strip_prefix('[') {
+            for attribute in attributes.trim_end().trim_end_matches(']').split(',') {
+                let attribute = attribute.trim();
+                if attribute == "private" {
+                    private = true;
+                } else if let Some(text) = attribute
+                    .strip_prefix("doc(")
+                    .and_then(|text| text.strip_suffix(')'))
+                {
+                    doc = Some(text.trim_matches(['"', '\'']).to_string());
+                }
+            }
+            continue;
+        }
+
+        let description = doc
+            .take()
+            .or_else(|| (!comments.is_empty()).then(|| comments.join(" ")));
+        let is_private = std::mem::take(&mut private);
+        comments.clear();
+
+        let recipe = line.trim_start_matches('@');
+        let end = recipe
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(recipe.len());
+        let (name, rest) = recipe.split_at(end);
+        let Some((_, after)) = rest.split_once(':') else {
+            continue;
+        };
+        // Skip settings and assignments, like `set shell := ["bash"]` or `a := "b"`
+        if name.is_empty()
+            || after.starts_with('=')
+            || rest.contains(":=")
+            || ["set", "alias", "export", "import", "mod"].contains(&name)
+            || name.starts_with('_')
+            || is_private
+        {
+            continue;
+        }
+        tasks.push(Task {
+            name: name.to_string(),
+            description,
+        });
+    }
+    tasks
+}
+
+/// Find the scripts of a package.json, described by the command they run,
+/// without the `pre` and `post` scripts npm runs around the others
+fn parse_package_json(content: &str) -> Result<Vec<Task>, Error> {
+    let package: serde_json::Value = serde_json::from_str(content)?;
+    let Some(scripts) = package.get("scripts").and_then(|s| s.as_object()) else {
+        return Ok(Vec::new());
+    };
+
+    let is_hook = |name: &str| {
+        ["pre", "post"].iter().any(|prefix| {
+            name.strip_prefix(prefix)
+                .is_some_and(|script| scripts.contains_key(script))
+        })
+    };
+    Ok(scripts
+        .iter()
+        .filter(|(name, _)| !is_hook(name))
+        .map(|(name, command)| Task {
+            name: name.clone(),
+            description: command.as_str().map(str::to_string),
+        })
+        .collect())
+}
@@ -0,0 +1,110 @@
+use anyhow::Error;
+use eashy::Format;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::fs;
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::cli::Cli;
+
+/// Time given to editors to finish writing before the files are read again
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Regenerate the shell script every time one of the input files changes
+pub fn watch(cli: &Cli) -> Result<(), Error> {
+    let Some(output_file) = cli.get_output_file()? else {
+        return Err(Error::msg("Watch mode needs an output file, not stdout"));
+    };
+    if let Some(parent) = output_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let input = cli.get_input_file()?;
+    let input = input
+        .canonicalize()
+        .map_err(|e| Error::msg(format!("Failed to watch '{}': {}", input.display(), e)))?;
+    // Watch the parent directory of a single file, so that editors replacing
+    // the file on save are still noticed
+    let watched_dir = if input.is_dir() {
+        input.clone()
+    } else {
+        input.parent().map(Path::to_path_buf).unwrap_or_default()
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)
+        .map_err(|e| Error::msg(format!("Failed to start file watcher: {}", e)))?;
+    watcher
+        .watch(&watched_dir, RecursiveMode::NonRecursive)
+        .map_err(|e| {
+            Error::msg(format!(
+                "Failed to watch '{}': {}",
+                watched_dir.display(),
+                e
+            ))
+        })?;
+
+    regenerate(cli, &output_file);
+    if !cli.quiet {
+        println!(
+            "👀 Watching {} for changes (Ctrl+C to stop)",
+            input.display()
+        );
+    }
+
+    for event in &rx {
+        match event {
+            Ok(event) if is_relevant(&event, &input) => {
+                thread::sleep(DEBOUNCE);
+                while rx.try_recv().is_ok() {}
+                regenerate(cli, &output_file);
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("❌ File watcher error: {}", e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Check if an event modifies the watched file, or a definition file of the watched directory
+fn is_relevant(event: &Event, input: &Path) -> bool {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return false;
+    }
+    event.paths.iter().any(|path| {
+        if input.is_dir() {
+            Format::from_path(path).is_some()
+        } else {
+            path == input
+        }
+    })
+}
+
+/// Generate the script, only writing it when its content changed
+fn regenerate(cli: &Cli, output_file: &Path) {
+    let output = match crate::build_script(cli) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return;
+        }
+    };
+
+    if fs::read_to_string(output_file).is_ok_and(|current| current == output) {
+        if !cli.quiet {
+            println!("💤 No changes in the generated script");
+        }
+        return;
+    }
+
+    match crate::write_output(output_file, &output) {
+        Ok(()) if !cli.quiet => {
+            println!("✅ Shell script regenerated at: {}", output_file.display())
+        }
+        Ok(()) => {}
+        Err(e) => eprintln!("❌ {}", e),
+    }
+}
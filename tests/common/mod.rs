@@ -0,0 +1,114 @@
+//! Helpers shared by the integration tests, running the eashy binary and the
+//! generated scripts
+
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Shells supported by the generated scripts
+pub const SHELLS: [&str; 2] = ["bash", "zsh"];
+
+pub struct Output {
+    pub stdout: String,
+    pub stderr: String,
+    pub status: i32,
+}
+
+pub fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+pub fn fixture(name: &str) -> PathBuf {
+    root().join("tests").join("fixtures").join(name)
+}
+
+/// Run eashy with the given arguments, panicking if it fails
+pub fn eashy(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_eashy"))
+        .args(args)
+        .output()
+        .expect("failed to run eashy");
+    assert!(
+        output.status.success(),
+        "eashy {:?} failed:\n{}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("eashy output is not UTF-8")
+}
+
+/// Generate the script of a KDL file, without the source lines of its
+/// header which depend on where the repository is
+pub fn generate(kdl: &Path) -> String {
+    eashy(&["-q", "-f", kdl.to_str().unwrap(), "-o", "-"])
+        .lines()
+        .filter(|line| !line.starts_with("# Source: "))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+/// Generate the script of a fixture into a file, to source it from shells
+pub fn generate_fixture_script(name: &str) -> PathBuf {
+    let script =
+        Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("{}.sh", name.replace('/', "_")));
+    fs::write(&script, generate(&fixture(name))).unwrap();
+    script
+}
+
+/// Get the supported shells installed on this machine
+pub fn installed_shells() -> Vec<&'static str> {
+    let shells: Vec<&str> = SHELLS
+        .into_iter()
+        .filter(|shell| {
+            Command::new(shell)
+                .args(["-c", "true"])
+                .output()
+                .is_ok_and(|output| output.status.success())
+        })
+        .collect();
+    for shell in SHELLS.iter().filter(|shell| !shells.contains(shell)) {
+        eprintln!("{} is not installed, skipping it", shell);
+    }
+    shells
+}
+
+/// Source a script in a shell and run a command line, without colors in its outputs
+pub fn run(shell: &str, script: &Path, command_line: &str) -> Output {
+    let output = Command::new(shell)
+        .arg("-c")
+        .arg(format!(". '{}'\n{}", script.display(), command_line))
+        .output()
+        .expect("failed to run the shell");
+    Output {
+        stdout: strip_colors(&String::from_utf8_lossy(&output.stdout)),
+        stderr: strip_colors(&String::from_utf8_lossy(&output.stderr)),
+        status: output.status.code().unwrap_or(-1),
+    }
+}
+
+fn strip_colors(s: &str) -> String {
+    let mut output = String::new();
+    let mut rest = s;
+    while let Some(start) = rest.find("\x1b[") {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        rest = rest.find('m').map_or("", |end| &rest[end + 1..]);
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Run eashy with the given arguments, whether it fails or not
+pub fn eashy_output(args: &[&str]) -> Output {
+    let output = Command::new(env!("CARGO_BIN_EXE_eashy"))
+        .args(args)
+        .output()
+        .expect("failed to run eashy");
+    Output {
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        status: output.status.code().unwrap_or(-1),
+    }
+}
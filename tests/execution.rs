@@ -0,0 +1,668 @@
+//! Source the generated scripts in bash and zsh, and check the behavior of
+//! the generated functions: help text, error messages and exit codes.
+
+mod common;
+
+/// Run each command line in every installed shell, checking its exit status
+/// and that its outputs contain the expected texts
+fn check(fixture: &str, cases: &[(&str, i32, &str, &str)]) {
+    let script = common::generate_fixture_script(fixture);
+    for shell in common::installed_shells() {
+        for (command_line, status, stdout, stderr) in cases {
+            let output = common::run(shell, &script, command_line);
+            let context = format!(
+                "[{}] {}\nstdout: {}\nstderr: {}",
+                shell, command_line, output.stdout, output.stderr
+            );
+            assert_eq!(output.status, *status, "unexpected status for {}", context);
+            assert!(
+                output.stdout.contains(stdout),
+                "stdout should contain {:?} for {}",
+                stdout,
+                context
+            );
+            assert!(
+                output.stderr.contains(stderr),
+                "stderr should contain {:?} for {}",
+                stderr,
+                context
+            );
+        }
+    }
+}
+
+#[test]
+fn required_argument() {
+    check(
+        "arguments.kdl",
+        &[
+            ("args required a", 0, "value=[a]", ""),
+            ("args required", 1, "", "Error: value is required"),
+            ("args required a b", 1, "", "Error: Too many arguments"),
+        ],
+    );
+}
+
+#[test]
+fn zero_or_one_argument() {
+    check(
+        "arguments.kdl",
+        &[
+            ("args optional", 0, "value=[]", ""),
+            ("args optional a", 0, "value=[a]", ""),
+            ("args optional a b", 1, "", "Error: Too many arguments"),
+        ],
+    );
+}
+
+#[test]
+fn zero_or_more_arguments() {
+    check(
+        "arguments.kdl",
+        &[
+            ("args zero_more", 0, "values=[]", ""),
+            ("args zero_more a", 0, "values=[a]", ""),
+            ("args zero_more a b c", 0, "values=[a b c]", ""),
+        ],
+    );
+}
+
+#[test]
+fn one_or_more_arguments() {
+    check(
+        "arguments.kdl",
+        &[
+            (
+                "args one_more",
+                1,
+                "",
+                "Error: values is required at least once",
+            ),
+            ("args one_more a", 0, "values=[a]", ""),
+            ("args one_more a b c", 0, "values=[a b c]", ""),
+        ],
+    );
+}
+
+#[test]
+fn optional_argument_before_required_one() {
+    check(
+        "arguments.kdl",
+        &[
+            (
+                "args optional_then_required a",
+                0,
+                "first=[] second=[a]",
+                "",
+            ),
+            (
+                "args optional_then_required a b",
+                0,
+                "first=[a] second=[b]",
+                "",
+            ),
+            (
+                "args optional_then_required",
+                1,
+                "",
+                "Error: second is required",
+            ),
+            (
+                "args optional_then_required a b c",
+                1,
+                "",
+                "Error: Too many arguments",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn variadic_argument_before_required_one() {
+    check(
+        "arguments.kdl",
+        &[
+            (
+                "args zero_more_then_required a",
+                0,
+                "first=[] second=[a]",
+                "",
+            ),
+            (
+                "args zero_more_then_required a b c",
+                0,
+                "first=[a b] second=[c]",
+                "",
+            ),
+            (
+                "args zero_more_then_required",
+                1,
+                "",
+                "Error: second is required",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn required_argument_before_variadic_one() {
+    check(
+        "arguments.kdl",
+        &[
+            (
+                "args required_then_one_more a b c",
+                0,
+                "first=[a] rest=[b c]",
+                "",
+            ),
+            (
+                "args required_then_one_more a",
+                1,
+                "",
+                "Error: rest is required at least once",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn optional_argument_before_variadic_one() {
+    check(
+        "arguments.kdl",
+        &[
+            ("args optional_then_one_more a", 0, "first=[] rest=[a]", ""),
+            (
+                "args optional_then_one_more a b c",
+                0,
+                "first=[a] rest=[b c]",
+                "",
+            ),
+            (
+                "args optional_then_one_more",
+                1,
+                "",
+                "Error: rest is required at least once",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn options() {
+    check(
+        "options.kdl",
+        &[
+            (
+                "opts",
+                0,
+                "name=[world] n=[42] empty=[] verbose=[false] enabled=[true]",
+                "",
+            ),
+            (
+                "opts --verbose --enabled --name you -n 7",
+                0,
+                "name=[you] n=[7] empty=[] verbose=[true] enabled=[false]",
+                "",
+            ),
+            ("opts --name", 1, "", "Error: --name requires a value"),
+            ("opts --nope", 1, "", "Error: Unknown option: --nope"),
+            ("opts extra", 1, "", "Error: Too many arguments"),
+            (
+                "mixed --lines 3 file.txt",
+                0,
+                "file=[file.txt] lines=[3]",
+                "",
+            ),
+            ("mixed file.txt", 0, "file=[file.txt] lines=[10]", ""),
+        ],
+    );
+}
+
+#[test]
+fn help() {
+    check(
+        "arguments.kdl",
+        &[
+            ("args --help", 0, "Usage: args <subcommand> [-h|--help]", ""),
+            ("args -h", 0, "  required                 Required", ""),
+            (
+                "args required --help",
+                0,
+                "Usage: args required <value> [-h|--help]",
+                "",
+            ),
+            (
+                "args one_more -h",
+                0,
+                "Usage: args one_more <values> [<values> ...] [-h|--help]",
+                "",
+            ),
+            (
+                "args optional_then_required -h",
+                0,
+                "Usage: args optional_then_required [<first>] <second> [-h|--help]",
+                "",
+            ),
+            (
+                "args zero_more -h",
+                0,
+                "Usage: args zero_more [<values> ...] [-h|--help]",
+                "",
+            ),
+        ],
+    );
+    check(
+        "options.kdl",
+        &[
+            (
+                "opts --help",
+                0,
+                "Usage: opts [--name <name>] [-n <n>] [--empty <empty>] [--verbose] [--enabled] [-h|--help]",
+                "",
+            ),
+            ("opts --help", 0, "  --name      Who to greet", ""),
+        ],
+    );
+}
+
+#[test]
+fn unknown_subcommands() {
+    check(
+        "nested.kdl",
+        &[
+            ("top nope", 1, "", "Error: Unknown subcommand: nope"),
+            ("top --nope", 1, "", "Error: Unknown option: --nope"),
+            ("top middle nope", 1, "", "Try 'top middle --help'"),
+        ],
+    );
+}
+
+#[test]
+fn nested_subcommands() {
+    check(
+        "nested.kdl",
+        &[
+            ("top middle bottom x", 0, "bottom [x]", ""),
+            ("top leaf", 0, "leaf", ""),
+            ("top middle -h", 0, "  bottom      Bottom level", ""),
+        ],
+    );
+}
+
+#[test]
+fn command_prefixes() {
+    check(
+        "prefixes.kdl",
+        &[
+            ("until_error", 1, "one\n", ""),
+            ("until_success", 0, "one\n", ""),
+            ("all", 0, "one\n", ""),
+        ],
+    );
+    let script = common::generate_fixture_script("prefixes.kdl");
+    for shell in common::installed_shells() {
+        assert!(
+            !common::run(shell, &script, "until_error")
+                .stdout
+                .contains("two")
+        );
+        assert!(
+            !common::run(shell, &script, "until_success")
+                .stdout
+                .contains("two")
+        );
+    }
+}
+
+#[test]
+fn imported_tasks() {
+    check(
+        "tasks/tasks.kdl",
+        &[
+            ("proj build", 0, "building with -O2", ""),
+            ("proj test ARGS=fast", 0, "testing fast", ""),
+            // Options are given to make as they are
+            ("proj clean -n", 0, "echo cleaning", ""),
+            (
+                "proj test --no-print-directory ARGS='a b'",
+                0,
+                "testing a b",
+                "",
+            ),
+            ("proj -h", 0, "  build       Build the project", ""),
+            ("proj -h", 0, "  test        Run the tests", ""),
+            ("proj site -h", 0, "  deploy      Deploy the site", ""),
+            ("proj nope", 1, "", "Error: Unknown subcommand: nope"),
+        ],
+    );
+}
+
+#[test]
+fn body_lines_and_scripts() {
+    check(
+        "scripts.kdl",
+        &[
+            ("syntax quoted world", 0, "Hello world .venv\n", ""),
+            ("syntax raw abc", 0, "$name has 3 characters\n", ""),
+            ("syntax semicolons", 0, "one\ntwo\n", ""),
+            ("syntax braces x", 0, "Hello {a-x}\nHello {b-x}\n", ""),
+            (
+                "syntax heredoc x",
+                0,
+                "  Hello x\nNot expanded: $name\n",
+                "",
+            ),
+            ("syntax chained", 1, "first\n", ""),
+        ],
+    );
+    let script = common::generate_fixture_script("scripts.kdl");
+    for shell in common::installed_shells() {
+        assert!(
+            !common::run(shell, &script, "syntax chained")
+                .stdout
+                .contains("not printed")
+        );
+    }
+}
+
+#[test]
+fn default_body() {
+    check(
+        "mixed.kdl",
+        &[
+            ("mixed", 0, "default body\n", ""),
+            ("mixed sub", 0, "sub\n", ""),
+            ("mixed run", 0, "run subcommand\n", ""),
+            ("mixed -h", 0, "mixed [<subcommand>] [-h|--help]", ""),
+            ("mixed nope", 1, "", "Error: Unknown subcommand: nope"),
+            ("chained", 1, "", ""),
+        ],
+    );
+    let script = common::generate_fixture_script("mixed.kdl");
+    for shell in common::installed_shells() {
+        assert!(
+            !common::run(shell, &script, "chained")
+                .stdout
+                .contains("not printed")
+        );
+    }
+}
+
+#[test]
+fn explicit_subcommands() {
+    check(
+        "mixed.kdl",
+        &[
+            ("flow status", 0, "status\n", ""),
+            ("flow log", 0, "", ""),
+            ("flow greet you", 0, "Hello you\n", ""),
+            ("flow -h", 0, "  status      Show the status", ""),
+            ("flow greet", 1, "", "name is required"),
+        ],
+    );
+}
+
+#[test]
+fn global_options() {
+    check(
+        "global.kdl",
+        &[
+            ("deploy status", 0, "profile=dev verbose=false\n", ""),
+            (
+                "deploy --verbose --profile prod status",
+                0,
+                "profile=prod verbose=true\n",
+                "",
+            ),
+            (
+                "deploy --profile prod cloud --region us push app",
+                0,
+                "push app to us with prod\n",
+                "",
+            ),
+            (
+                "deploy --profile",
+                1,
+                "",
+                "Error: --profile requires a value",
+            ),
+            (
+                "deploy --nope status",
+                1,
+                "",
+                "Error: Unknown option: --nope",
+            ),
+            (
+                "deploy status --verbose",
+                1,
+                "",
+                "Error: Unknown option: --verbose",
+            ),
+            (
+                "deploy -h",
+                0,
+                "deploy [--verbose] [--profile <profile>] <subcommand> [-h|--help]",
+                "",
+            ),
+            (
+                "deploy -h",
+                0,
+                "Global options:\n  --verbose   Print more details",
+                "",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn argument_sets() {
+    check(
+        "sets.kdl",
+        &[
+            (
+                "release build app --env prod",
+                0,
+                "build app for prod (dry run: false)\n",
+                "",
+            ),
+            (
+                "release publish --dry_run",
+                0,
+                "publish to dev (dry run: true)\n",
+                "",
+            ),
+            (
+                "release publish -h",
+                0,
+                "  --env       Target environment",
+                "",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn variables() {
+    check(
+        "vars.kdl",
+        &[
+            (
+                "images push",
+                0,
+                "docker push ghcr.io/acme/app:latest\n",
+                "",
+            ),
+            (
+                "images push --tag v1",
+                0,
+                "docker push ghcr.io/acme/app:v1\n",
+                "",
+            ),
+            ("images -h", 0, "Push an image to ghcr.io/acme", ""),
+            ("images push -h", 0, "Tag of ghcr.io/acme/app", ""),
+            ("images format", 0, "docker ps --format {{.Names}}\n", ""),
+        ],
+    );
+}
+
+#[test]
+fn conditions() {
+    check(
+        "conditions.kdl",
+        &[
+            (
+                "cluster pods",
+                1,
+                "",
+                "Error: 'cluster pods' requires eashy-missing-program\n",
+            ),
+            ("cluster pods -h", 0, "Checked when running it", ""),
+            ("cluster nodes", 1, "", "Error: Unknown subcommand: nodes"),
+            ("cluster shell", 0, "shell\n", ""),
+            (
+                "remote host",
+                1,
+                "",
+                "Error: 'remote' requires a host matching 'eashy-missing-host-*'\n",
+            ),
+            ("remote -h", 0, "Show the host", ""),
+            ("legacy", 1, "", "Error: 'legacy' requires windows\n"),
+        ],
+    );
+}
+
+#[test]
+fn hooks() {
+    check(
+        "hooks.kdl",
+        &[
+            ("work ok", 0, "setup\nok\nafter ok\ncleanup\n", ""),
+            (
+                "work fail",
+                1,
+                "setup\nrunning\nfailed with 1\ncleanup\n",
+                "",
+            ),
+            ("guard=1 work guarded", 0, "setup\nguarded\ncleanup\n", ""),
+            ("work guarded", 1, "setup\nfailed with 1\ncleanup\n", ""),
+            ("work ok -h", 0, "Succeed, then run the after hook", ""),
+        ],
+    );
+}
+
+#[test]
+fn working_directory_and_environment() {
+    let root = common::root().display().to_string();
+    let fixtures = common::fixture("").display().to_string();
+    let fixtures = fixtures.trim_end_matches('/');
+    let settings = common::fixture("settings").display().to_string();
+    let show = format!("{}\nhello from eashy\n", settings);
+    let caller = format!("{}\nhello from eashy\n{}\n\n", settings, root);
+    let enter = format!("entered\n{}\ntrue\n", settings);
+    check(
+        "settings/settings.kdl",
+        &[
+            ("fixtures show", 0, &show, ""),
+            ("fixtures show; pwd; echo \"$GREETING\"", 0, &caller, ""),
+            ("fixtures parent", 0, fixtures, ""),
+            ("fixtures root", 0, &root, ""),
+            ("fixtures fail", 1, "", ""),
+            ("fixtures missing", 1, "", "missing"),
+            ("fixtures enter; pwd; echo \"$ENTERED\"", 0, &enter, ""),
+        ],
+    );
+}
+
+/// Run a command line in a shell sourcing a script generated with `--project`,
+/// with its own home directory
+fn run_with_project_hook(
+    shell: &str,
+    home: &std::path::Path,
+    command_line: &str,
+) -> common::Output {
+    let output = std::process::Command::new(shell)
+        .env("HOME", home)
+        .current_dir(home)
+        .arg("-c")
+        .arg(format!(". ./eashy.sh\n{}", command_line))
+        .output()
+        .expect("failed to run the shell");
+    common::Output {
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        status: output.status.code().unwrap_or(-1),
+    }
+}
+
+#[test]
+fn project_files_are_loaded_once_allowed() {
+    let home = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("project-home");
+    let _ = std::fs::remove_dir_all(&home);
+    let project = home.join("project");
+    std::fs::create_dir_all(&project).unwrap();
+    let kdl = project.join(".eashy.kdl");
+    std::fs::write(&kdl, "build { echo \"project build\"; }\n").unwrap();
+
+    let eashy = |dir: &std::path::Path, args: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_eashy"))
+            .env("HOME", &home)
+            .current_dir(dir)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "eashy {:?} failed", args);
+    };
+    let global = common::fixture("nested.kdl");
+    eashy(
+        &home,
+        &[
+            "-q",
+            "--project",
+            "-f",
+            global.to_str().unwrap(),
+            "-o",
+            "eashy.sh",
+        ],
+    );
+
+    let load = "cd project && _eashy_project_hook; build";
+    for shell in common::installed_shells() {
+        let output = run_with_project_hook(shell, &home, load);
+        assert_ne!(output.status, 0, "[{}] unknown files are not loaded", shell);
+        assert!(output.stderr.contains("is not allowed, run `eashy allow`"));
+    }
+
+    eashy(&project, &["allow", "-q"]);
+    for shell in common::installed_shells() {
+        let output = run_with_project_hook(shell, &home, load);
+        assert_eq!(
+            output.stdout, "project build\n",
+            "[{}] {}",
+            shell, output.stderr
+        );
+    }
+
+    // Functions replaced by project commands are back when leaving the project
+    let shadow = "build() { echo \"global build\"; }\n\
+        cd project && _eashy_project_hook; build; cd .. && _eashy_project_hook; build";
+    for shell in common::installed_shells() {
+        let output = run_with_project_hook(shell, &home, shadow);
+        assert_eq!(
+            output.stdout, "project build\nglobal build\n",
+            "[{}] {}",
+            shell, output.stderr
+        );
+    }
+
+    // Changed files need to be allowed again
+    std::fs::write(&kdl, "build { echo \"changed\"; }\n").unwrap();
+    for shell in common::installed_shells() {
+        let output = run_with_project_hook(shell, &home, load);
+        assert!(
+            !output.stdout.contains("changed"),
+            "[{}] changed files are not loaded",
+            shell
+        );
+        assert!(output.stderr.contains("is not allowed"));
+    }
+}
@@ -0,0 +1,39 @@
+#!/bin/bash
+# Dotfiles to import, run against the imported commands by tests/import.rs
+
+export EDITOR=vim
+
+# Greet someone
+greet() {
+    echo "Hello ${1}, from $2!"
+}
+
+# Count down from a number
+function countdown {
+    n=$1
+    while [ "$n" -gt 0 ]; do
+        printf '%s ' "$n"
+        n=$((n - 1))
+    done
+    echo 'liftoff; {done}'
+}
+
+check_dir()
+{
+    if [ -d "$1" ]; then
+        echo "$1 is a directory"
+    else
+        echo "$1 is missing" >&2
+        return 3
+    fi
+}
+
+quick() { echo "quick $1"; }
+
+forward() {
+    echo "all: $@"
+}
+
+# List the arguments
+alias show_args='printf "<%s>" '
+alias hello="echo hello world"
@@ -0,0 +1,82 @@
+//! Compare the scripts generated from every fixture, and the documentation
+//! generated from the example, with the checked-in golden files. Run with `UPDATE_GOLDEN=1` to update them after a change in
+//! the generator.
+
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+
+fn inputs() -> Vec<PathBuf> {
+    let mut inputs: Vec<PathBuf> = fs::read_dir(common::root().join("tests").join("fixtures"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "kdl"))
+        .collect();
+    inputs.sort();
+    // The files shipped with eashy are fixtures too
+    inputs.push(common::root().join("default.kdl"));
+    inputs.push(common::root().join("example.kdl"));
+    inputs
+}
+
+#[test]
+fn generated_scripts_match_golden_files() {
+    let outputs = inputs()
+        .iter()
+        .map(|input| {
+            let name = input.file_stem().unwrap().to_str().unwrap();
+            (format!("{}.sh", name), common::generate(input))
+        })
+        .collect();
+    check_golden_files(outputs);
+}
+
+#[test]
+fn generated_docs_match_golden_files() {
+    let example = common::root().join("example.kdl");
+    let outputs = [("markdown", "md"), ("man", "1"), ("html", "html")]
+        .iter()
+        .map(|(format, extension)| {
+            let docs =
+                common::eashy(&["-f", example.to_str().unwrap(), "docs", "--format", format]);
+            (format!("example.{}", extension), docs)
+        })
+        .collect();
+    check_golden_files(outputs);
+}
+
+/// Compare outputs with the golden files of the same name, or update them
+fn check_golden_files(outputs: Vec<(String, String)>) {
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    let golden_dir = common::root().join("tests").join("golden");
+    let mut mismatches = Vec::new();
+
+    for (name, output) in outputs {
+        let golden = golden_dir.join(&name);
+
+        if update {
+            fs::write(&golden, &output).unwrap();
+            continue;
+        }
+
+        let expected = fs::read_to_string(&golden).unwrap_or_default();
+        if output != expected {
+            let line = output
+                .lines()
+                .zip(expected.lines())
+                .position(|(a, b)| a != b)
+                .unwrap_or(std::cmp::min(
+                    output.lines().count(),
+                    expected.lines().count(),
+                ));
+            mismatches.push(format!("{} (first difference at line {})", name, line + 1));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "Generated outputs differ from their golden files, run with UPDATE_GOLDEN=1 to update them:\n  {}",
+        mismatches.join("\n  ")
+    );
+}
@@ -0,0 +1,493 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+args() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Argument prefixes\n\n\\033[1;32mUsage:\\033[0m \\033[1;36margs \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mrequired               \\033[0m  Required\n  \\033[1;36moptional               \\033[0m  Zero or one\n  \\033[1;36mzero_more              \\033[0m  Zero or more\n  \\033[1;36mone_more               \\033[0m  One or more\n  \\033[1;36moptional_then_required \\033[0m  \n  \\033[1;36mzero_more_then_required\\033[0m  \n  \\033[1;36mrequired_then_one_more \\033[0m  \n  \\033[1;36moptional_then_one_more \\033[0m  \n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help             \\033[0m  Show help information\n"
+            return
+            ;;
+        required) _args_required_ "$@";;
+        optional) _args_optional_ "$@";;
+        zero_more) _args_zero_more_ "$@";;
+        one_more) _args_one_more_ "$@";;
+        optional_then_required) _args_optional_then_required_ "$@";;
+        zero_more_then_required) _args_zero_more_then_required_ "$@";;
+        required_then_one_more) _args_required_then_one_more_ "$@";;
+        optional_then_one_more) _args_optional_then_one_more_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36margs \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'args --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36margs \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'args --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_args_required_() {
+    value=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Required\n\n\\033[1;32mUsage:\\033[0m \\033[1;36margs required \\033[0;36m<value> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mvalue     \\033[0m  VALUE\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36margs required \\033[0;36m<value> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'args required --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        value="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36margs required \\033[0;36m<value> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'args required --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$value" ]; then
+        printf "\033[1;31mError:\033[0m value is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36margs required \\033[0;36m<value> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'args required --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "value=[$value]"
+}
+
+_args_optional_() {
+    value=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Zero or one\n\n\\033[1;32mUsage:\\033[0m \\033[1;36margs optional \\033[0;36m[<value>] [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mvalue     \\033[0m  VALUE\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36margs optional \\033[0;36m[<value>] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'args optional --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        if [ $# -gt 0 ]; then
+                            value="$1"
+                            shift
+                        fi
+                        _pos_count=$((_pos_count + 1))
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36margs optional \\033[0;36m[<value>] [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'args optional --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "value=[$value]"
+}
+
+_args_zero_more_() {
+    values=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Zero or more\n\n\\033[1;32mUsage:\\033[0m \\033[1;36margs zero_more \\033[0;36m[<values> ...] [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mvalues    \\033[0m  VALUES\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36margs zero_more \\033[0;36m[<values> ...] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'args zero_more --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        while [ $# -gt 0 ] && [ "${1#-}" = "$1" ]; do
+                            if [ -z "$values" ]; then
+                                values="$1"
+                            else
+                                values="$values $1"
+                            fi
+                            shift
+                        done
+                        _pos_count=$((_pos_count + 1))
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36margs zero_more \\033[0;36m[<values> ...] [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'args zero_more --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "values=[$values]"
+}
+
+_args_one_more_() {
+    values=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "One or more\n\n\\033[1;32mUsage:\\033[0m \\033[1;36margs one_more \\033[0;36m<values> [<values> ...] [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mvalues    \\033[0m  VALUES\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36margs one_more \\033[0;36m<values> [<values> ...] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'args one_more --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        while [ $# -gt 0 ] && [ "${1#-}" = "$1" ]; do
+                            if [ -z "$values" ]; then
+                                values="$1"
+                            else
+                                values="$values $1"
+                            fi
+                            shift
+                        done
+                        _pos_count=$((_pos_count + 1))
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36margs one_more \\033[0;36m<values> [<values> ...] [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'args one_more --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$values" ]; then
+        printf "\033[1;31mError:\033[0m values is required at least once\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36margs one_more \\033[0;36m<values> [<values> ...] [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'args one_more --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "values=[$values]"
+}
+
+_args_optional_then_required_() {
+    first=""
+    second=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36margs optional_then_required \\033[0;36m[<first>] <second> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mfirst     \\033[0m  FIRST\n  \\033[1;36msecond    \\033[0m  SECOND\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36margs optional_then_required \\033[0;36m[<first>] <second> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'args optional_then_required --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        if [ $# -gt 1 ]; then
+                            first="$1"
+                            shift
+                        fi
+                        _pos_count=$((_pos_count + 1))
+                        ;;
+                    1)
+                        second="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36margs optional_then_required \\033[0;36m[<first>] <second> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'args optional_then_required --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$second" ]; then
+        printf "\033[1;31mError:\033[0m second is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36margs optional_then_required \\033[0;36m[<first>] <second> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'args optional_then_required --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "first=[$first] second=[$second]"
+}
+
+_args_zero_more_then_required_() {
+    first=""
+    second=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36margs zero_more_then_required \\033[0;36m[<first> ...] <second> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mfirst     \\033[0m  FIRST\n  \\033[1;36msecond    \\033[0m  SECOND\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36margs zero_more_then_required \\033[0;36m[<first> ...] <second> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'args zero_more_then_required --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        while [ $# -gt 1 ] && [ "${1#-}" = "$1" ]; do
+                            if [ -z "$first" ]; then
+                                first="$1"
+                            else
+                                first="$first $1"
+                            fi
+                            shift
+                        done
+                        _pos_count=$((_pos_count + 1))
+                        ;;
+                    1)
+                        second="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36margs zero_more_then_required \\033[0;36m[<first> ...] <second> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'args zero_more_then_required --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$second" ]; then
+        printf "\033[1;31mError:\033[0m second is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36margs zero_more_then_required \\033[0;36m[<first> ...] <second> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'args zero_more_then_required --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "first=[$first] second=[$second]"
+}
+
+_args_required_then_one_more_() {
+    first=""
+    rest=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36margs required_then_one_more \\033[0;36m<first> <rest> [<rest> ...] [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mfirst     \\033[0m  FIRST\n  \\033[1;36mrest      \\033[0m  REST\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36margs required_then_one_more \\033[0;36m<first> <rest> [<rest> ...] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'args required_then_one_more --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        first="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    1)
+                        while [ $# -gt 0 ] && [ "${1#-}" = "$1" ]; do
+                            if [ -z "$rest" ]; then
+                                rest="$1"
+                            else
+                                rest="$rest $1"
+                            fi
+                            shift
+                        done
+                        _pos_count=$((_pos_count + 1))
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36margs required_then_one_more \\033[0;36m<first> <rest> [<rest> ...] [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'args required_then_one_more --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$first" ]; then
+        printf "\033[1;31mError:\033[0m first is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36margs required_then_one_more \\033[0;36m<first> <rest> [<rest> ...] [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'args required_then_one_more --help' for more information.\n" >&2
+        return 1
+    fi
+    if [ -z "$rest" ]; then
+        printf "\033[1;31mError:\033[0m rest is required at least once\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36margs required_then_one_more \\033[0;36m<first> <rest> [<rest> ...] [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'args required_then_one_more --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "first=[$first] rest=[$rest]"
+}
+
+_args_optional_then_one_more_() {
+    first=""
+    rest=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36margs optional_then_one_more \\033[0;36m[<first>] <rest> [<rest> ...] [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mfirst     \\033[0m  FIRST\n  \\033[1;36mrest      \\033[0m  REST\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36margs optional_then_one_more \\033[0;36m[<first>] <rest> [<rest> ...] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'args optional_then_one_more --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        if [ $# -gt 1 ]; then
+                            first="$1"
+                            shift
+                        fi
+                        _pos_count=$((_pos_count + 1))
+                        ;;
+                    1)
+                        while [ $# -gt 0 ] && [ "${1#-}" = "$1" ]; do
+                            if [ -z "$rest" ]; then
+                                rest="$1"
+                            else
+                                rest="$rest $1"
+                            fi
+                            shift
+                        done
+                        _pos_count=$((_pos_count + 1))
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36margs optional_then_one_more \\033[0;36m[<first>] <rest> [<rest> ...] [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'args optional_then_one_more --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$rest" ]; then
+        printf "\033[1;31mError:\033[0m rest is required at least once\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36margs optional_then_one_more \\033[0;36m[<first>] <rest> [<rest> ...] [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'args optional_then_one_more --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "first=[$first] rest=[$rest]"
+}
+
+_completions_args_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "args") eval 'array=(
+            "required:                 Required"
+            "optional:                 Zero or one"
+            "zero_more:                Zero or more"
+            "one_more:                 One or more"
+            "optional_then_required:   "
+            "zero_more_then_required:  "
+            "required_then_one_more:   "
+            "optional_then_one_more:   "
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:                       Show help information" "--help:                   Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh args
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash args
+fi
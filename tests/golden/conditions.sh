@@ -0,0 +1,258 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+cluster() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Cluster tasks\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mcluster \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mpods      \\033[0m  Checked when running it\n  \\033[1;36mshell     \\033[0m  Kept on every machine with a shell\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        pods) _cluster_pods_ "$@";;
+        shell) _cluster_shell_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mcluster \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'cluster --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mcluster \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'cluster --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_cluster_pods_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Checked when running it\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mcluster pods \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mcluster pods \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'cluster pods --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mcluster pods \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'cluster pods --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    if ! command -v 'eashy-missing-program' >/dev/null 2>&1; then
+        printf "\033[1;31mError:\033[0m 'cluster pods' requires eashy-missing-program\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "pods"
+}
+
+_cluster_shell_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Kept on every machine with a shell\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mcluster shell \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mcluster shell \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'cluster shell --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mcluster shell \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'cluster shell --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "shell"
+}
+
+_completions_cluster_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "cluster") eval 'array=(
+            "pods:        Checked when running it"
+            "shell:       Kept on every machine with a shell"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+remote() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    if [ "$subcmd" != "-h" ] && [ "$subcmd" != "--help" ]; then
+        case "$(uname -n)" in
+            eashy-missing-host-*) ;;
+            *)
+                printf "\033[1;31mError:\033[0m 'remote' requires a host matching 'eashy-missing-host-*'\n" >&2
+                return 1
+                ;;
+        esac
+    fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Group checked when running its subcommands\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mremote \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mhost      \\033[0m  Show the host\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        host) _remote_host_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mremote \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'remote --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mremote \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'remote --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_remote_host_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Show the host\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mremote host \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mremote host \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'remote host --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mremote host \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'remote host --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    uname -n
+}
+
+_completions_remote_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "remote") eval 'array=(
+            "host:        Show the host"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+legacy() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Only runs on Windows\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mlegacy \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mlegacy \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'legacy --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mlegacy \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'legacy --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    case "$(uname -s)" in
+        MINGW*|MSYS*|CYGWIN*) ;;
+        *)
+            printf "\033[1;31mError:\033[0m 'legacy' requires windows\n" >&2
+            return 1
+            ;;
+    esac
+    # Execute command
+    echo "legacy"
+}
+
+_completions_legacy_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh cluster
+    compdef _complete_zsh remote
+    compdef _complete_zsh legacy
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash cluster
+    complete -o default $no_sort -F _complete_bash remote
+    complete -o default $no_sort -F _complete_bash legacy
+fi
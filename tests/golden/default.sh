@@ -0,0 +1,186 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+venv() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Python venv management\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mvenv \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36minit      \\033[0m  Create a new virtual environment\n  \\033[1;36mactivate  \\033[0m  Activate the virtual environment\n  \\033[1;36mdeactivate\\033[0m  Deactivate the virtual environment\n  \\033[1;36mdelete    \\033[0m  Delete the virtual environment\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        init) _venv_init_ "$@";;
+        activate) _venv_activate_ "$@";;
+        deactivate) _venv_deactivate_ "$@";;
+        delete) _venv_delete_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'venv --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'venv --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_venv_init_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Create a new virtual environment\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mvenv init \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv init \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'venv init --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv init \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'venv init --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    python -m venv ".venv"
+    source ".venv/bin/activate"
+    echo "Activated virtual environment, version: $(python --version)"
+}
+
+_venv_activate_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Activate the virtual environment\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mvenv activate \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv activate \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'venv activate --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv activate \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'venv activate --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    source ".venv/bin/activate" && echo "Activated virtual environment, version: $(python --version)" || echo "Failed to activate virtual environment. Make sure .venv exists."
+}
+
+_venv_deactivate_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Deactivate the virtual environment\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mvenv deactivate \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv deactivate \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'venv deactivate --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv deactivate \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'venv deactivate --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    deactivate && echo "Deactivated virtual environment"
+}
+
+_venv_delete_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Delete the virtual environment\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mvenv delete \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv delete \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'venv delete --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mvenv delete \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'venv delete --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    deactivate
+    rm -rf ".venv" && echo "Removed virtual environment"
+}
+
+_completions_venv_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "venv") eval 'array=(
+            "init:        Create a new virtual environment"
+            "activate:    Activate the virtual environment"
+            "deactivate:  Deactivate the virtual environment"
+            "delete:      Delete the virtual environment"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh venv
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash venv
+fi
@@ -0,0 +1,218 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+deploy() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    verbose=false
+    profile="dev"
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            --verbose)
+                verbose=true
+                shift
+                ;;
+            --profile)
+                if [ $# -lt 2 ]; then
+                    printf "\033[1;31mError:\033[0m --profile requires a value\n\n" >&2
+                    printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy \\033[0;36m[--verbose] [--profile <profile>] <subcommand> [-h|--help]\\033[0m\n\n" >&2
+                    printf "Try 'deploy --help' for more information.\n" >&2
+                    return 1
+                fi
+                profile="$2"
+                shift 2
+                ;;
+            *)
+                break
+                ;;
+        esac
+    done
+
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Global options, given before the subcommand\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mdeploy \\033[0;36m[--verbose] [--profile <profile>] <subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mstatus    \\033[0m  Show the settings\n  \\033[1;36mcloud     \\033[0m  Nested group with its own global option\n\n\\033[1;32mGlobal options:\\033[0m\n  \\033[1;36m--verbose \\033[0m  Print more details\n  \\033[1;36m--profile \\033[0m  Profile to deploy with\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        status) _deploy_status_ "$@";;
+        cloud) _deploy_cloud_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy \\033[0;36m[--verbose] [--profile <profile>] <subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'deploy --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy \\033[0;36m[--verbose] [--profile <profile>] <subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'deploy --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_deploy_status_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Show the settings\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mdeploy status \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy status \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'deploy status --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy status \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'deploy status --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "profile=$profile verbose=$verbose"
+}
+
+_deploy_cloud_() {
+    region="eu"
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            --region)
+                if [ $# -lt 2 ]; then
+                    printf "\033[1;31mError:\033[0m --region requires a value\n\n" >&2
+                    printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy cloud \\033[0;36m[--region <region>] <subcommand> [-h|--help]\\033[0m\n\n" >&2
+                    printf "Try 'deploy cloud --help' for more information.\n" >&2
+                    return 1
+                fi
+                region="$2"
+                shift 2
+                ;;
+            *)
+                break
+                ;;
+        esac
+    done
+
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Nested group with its own global option\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mdeploy cloud \\033[0;36m[--region <region>] <subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mpush      \\033[0m  \n\n\\033[1;32mGlobal options:\\033[0m\n  \\033[1;36m--region  \\033[0m  Cloud region\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        push) _deploy_cloud_push_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy cloud \\033[0;36m[--region <region>] <subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'deploy cloud --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy cloud \\033[0;36m[--region <region>] <subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'deploy cloud --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_deploy_cloud_push_() {
+    target=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36mdeploy cloud push \\033[0;36m<target> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mtarget    \\033[0m  TARGET\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy cloud push \\033[0;36m<target> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'deploy cloud push --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        target="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy cloud push \\033[0;36m<target> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'deploy cloud push --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$target" ]; then
+        printf "\033[1;31mError:\033[0m target is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36mdeploy cloud push \\033[0;36m<target> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'deploy cloud push --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "push $target to $region with $profile"
+}
+
+_completions_deploy_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "deploy") eval 'array=(
+            "status:      Show the settings"
+            "cloud:       Nested group with its own global option"
+            "--verbose:   Print more details"
+            "--profile:   <profile> Profile to deploy with"
+            )';;
+        "deploy cloud") eval 'array=(
+            "push:        "
+            "--region:    <region> Cloud region"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh deploy
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash deploy
+fi
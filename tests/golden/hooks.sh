@@ -0,0 +1,202 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+work() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Work in a temporary directory\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mwork \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mok        \\033[0m  Succeed, then run the after hook\n  \\033[1;36mfail      \\033[0m  Fail, keeping the exit status\n  \\033[1;36mguarded   \\033[0m  Fail in a before hook\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        ok) _work_ok_ "$@";;
+        fail) _work_fail_ "$@";;
+        guarded) _work_guarded_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mwork \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'work --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mwork \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'work --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_work_ok_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Succeed, then run the after hook\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mwork ok \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mwork ok \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'work ok --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mwork ok \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'work ok --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    local _status=0
+    while :; do
+        echo "setup" || { _status=$?; break; }
+        _work_ok__body_ || { _status=$?; break; }
+        echo "after ok" || { _status=$?; break; }
+        break
+    done
+    if [ $_status -ne 0 ]; then
+        echo "failed with $_status"
+    fi
+    echo "cleanup"
+    return $_status
+}
+
+_work_ok__body_() {
+    echo "ok"
+}
+
+_work_fail_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Fail, keeping the exit status\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mwork fail \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mwork fail \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'work fail --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mwork fail \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'work fail --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    local _status=0
+    while :; do
+        echo "setup" || { _status=$?; break; }
+        _work_fail__body_ || { _status=$?; break; }
+        echo "never printed" || { _status=$?; break; }
+        break
+    done
+    if [ $_status -ne 0 ]; then
+        echo "failed with $_status"
+    fi
+    echo "cleanup"
+    return $_status
+}
+
+_work_fail__body_() {
+    echo "running" || return $?
+    false || return $?
+    echo "never printed" || return $?
+}
+
+_work_guarded_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Fail in a before hook\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mwork guarded \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mwork guarded \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'work guarded --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mwork guarded \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'work guarded --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    local _status=0
+    while :; do
+        echo "setup" || { _status=$?; break; }
+        test -n "$guard" || { _status=$?; break; }
+        _work_guarded__body_ || { _status=$?; break; }
+        break
+    done
+    if [ $_status -ne 0 ]; then
+        echo "failed with $_status"
+    fi
+    echo "cleanup"
+    return $_status
+}
+
+_work_guarded__body_() {
+    echo "guarded"
+}
+
+_completions_work_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "work") eval 'array=(
+            "ok:          Succeed, then run the after hook"
+            "fail:        Fail, keeping the exit status"
+            "guarded:     Fail in a before hook"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh work
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash work
+fi
@@ -0,0 +1,345 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+mixed() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Runs its default body without a subcommand\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mmixed \\033[0;36m[<subcommand>] [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36msub       \\033[0m  Regular subcommand\n  \\033[1;36mrun       \\033[0m  Subcommand named run\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        "")
+            echo "default body"
+            ;;
+        sub) _mixed_sub_ "$@";;
+        run) _mixed_run_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed \\033[0;36m[<subcommand>] [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'mixed --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed \\033[0;36m[<subcommand>] [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'mixed --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_mixed_sub_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Regular subcommand\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mmixed sub \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed sub \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'mixed sub --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed sub \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'mixed sub --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "sub"
+}
+
+_mixed_run_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Subcommand named run\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mmixed run \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed run \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'mixed run --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed run \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'mixed run --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "run subcommand"
+}
+
+_completions_mixed_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "mixed") eval 'array=(
+            "sub:         Regular subcommand"
+            "run:         Subcommand named run"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+chained() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "\\033[1;32mUsage:\\033[0m \\033[1;36mchained \\033[0;36m[<subcommand>] [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36msub       \\033[0m  \n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        "")
+            false || return $?
+            echo "not printed" || return $?
+            ;;
+        sub) _chained_sub_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mchained \\033[0;36m[<subcommand>] [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'chained --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mchained \\033[0;36m[<subcommand>] [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'chained --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_chained_sub_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36mchained sub \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mchained sub \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'chained sub --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mchained sub \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'chained sub --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "sub"
+}
+
+_completions_chained_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "chained") eval 'array=(
+            "sub:         "
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+flow() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Subcommands declared with cmd, even without a block\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mflow \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mstatus    \\033[0m  Show the status\n  \\033[1;36mlog       \\033[0m  \n  \\033[1;36mgreet     \\033[0m  \n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        status) _flow_status_ "$@";;
+        log) _flow_log_ "$@";;
+        greet) _flow_greet_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mflow \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'flow --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mflow \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'flow --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_flow_status_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Show the status\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mflow status \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mflow status \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'flow status --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mflow status \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'flow status --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "status"
+}
+
+_flow_log_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36mflow log \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mflow log \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'flow log --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mflow log \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'flow log --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+}
+
+_flow_greet_() {
+    name=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "\\033[1;32mUsage:\\033[0m \\033[1;36mflow greet \\033[0;36m<name> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mname      \\033[0m  NAME\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mflow greet \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'flow greet --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        name="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36mflow greet \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'flow greet --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$name" ]; then
+        printf "\033[1;31mError:\033[0m name is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36mflow greet \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'flow greet --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "Hello $name"
+}
+
+_completions_flow_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "flow") eval 'array=(
+            "status:      Show the status"
+            "log:         "
+            "greet:       "
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh mixed
+    compdef _complete_zsh chained
+    compdef _complete_zsh flow
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash mixed
+    complete -o default $no_sort -F _complete_bash chained
+    complete -o default $no_sort -F _complete_bash flow
+fi
@@ -0,0 +1,172 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+top() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Top level\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mtop \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mmiddle    \\033[0m  Middle level\n  \\033[1;36mleaf      \\033[0m  Sibling leaf\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        middle) _top_middle_ "$@";;
+        leaf) _top_leaf_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mtop \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'top --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mtop \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'top --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_top_middle_() {
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Middle level\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mtop middle \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mbottom    \\033[0m  Bottom level\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        bottom) _top_middle_bottom_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mtop middle \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'top middle --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mtop middle \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'top middle --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_top_middle_bottom_() {
+    arg=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Bottom level\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mtop middle bottom \\033[0;36m<arg> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36marg       \\033[0m  ARG\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mtop middle bottom \\033[0;36m<arg> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'top middle bottom --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        arg="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36mtop middle bottom \\033[0;36m<arg> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'top middle bottom --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$arg" ]; then
+        printf "\033[1;31mError:\033[0m arg is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36mtop middle bottom \\033[0;36m<arg> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'top middle bottom --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "bottom [$arg]"
+}
+
+_top_leaf_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Sibling leaf\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mtop leaf \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mtop leaf \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'top leaf --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mtop leaf \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'top leaf --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "leaf"
+}
+
+_completions_top_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "top") eval 'array=(
+            "middle:      Middle level"
+            "leaf:        Sibling leaf"
+            )';;
+        "top middle") eval 'array=(
+            "bottom:      Bottom level"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh top
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash top
+fi
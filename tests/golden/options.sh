@@ -0,0 +1,196 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+opts() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    name="world"
+    n="42"
+    empty=""
+    verbose=false
+    enabled=true
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Options with defaults\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mopts \\033[0;36m[--name <name>] [-n <n>] [--empty <empty>] [--verbose] [--enabled] [-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m--name    \\033[0m  Who to greet\n  \\033[1;36m-n        \\033[0m  Short option\n  \\033[1;36m--empty   \\033[0m  EMPTY\n  \\033[1;36m--verbose \\033[0m  VERBOSE\n  \\033[1;36m--enabled \\033[0m  ENABLED\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            --name)
+                if [ $# -lt 2 ]; then
+                    printf "\033[1;31mError:\033[0m --name requires a value\n\n" >&2
+                    printf "\033[1;32mUsage:\033[0m \\033[1;36mopts \\033[0;36m[--name <name>] [-n <n>] [--empty <empty>] [--verbose] [--enabled] [-h|--help]\\033[0m\n\n" >&2
+                    printf "Try 'opts --help' for more information.\n" >&2
+                    return 1
+                fi
+                name="$2"
+                shift 2
+                ;;
+            -n)
+                if [ $# -lt 2 ]; then
+                    printf "\033[1;31mError:\033[0m -n requires a value\n\n" >&2
+                    printf "\033[1;32mUsage:\033[0m \\033[1;36mopts \\033[0;36m[--name <name>] [-n <n>] [--empty <empty>] [--verbose] [--enabled] [-h|--help]\\033[0m\n\n" >&2
+                    printf "Try 'opts --help' for more information.\n" >&2
+                    return 1
+                fi
+                n="$2"
+                shift 2
+                ;;
+            --empty)
+                if [ $# -lt 2 ]; then
+                    printf "\033[1;31mError:\033[0m --empty requires a value\n\n" >&2
+                    printf "\033[1;32mUsage:\033[0m \\033[1;36mopts \\033[0;36m[--name <name>] [-n <n>] [--empty <empty>] [--verbose] [--enabled] [-h|--help]\\033[0m\n\n" >&2
+                    printf "Try 'opts --help' for more information.\n" >&2
+                    return 1
+                fi
+                empty="$2"
+                shift 2
+                ;;
+            --verbose)
+                verbose=true
+                shift
+                ;;
+            --enabled)
+                enabled=false
+                shift
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mopts \\033[0;36m[--name <name>] [-n <n>] [--empty <empty>] [--verbose] [--enabled] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'opts --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mopts \\033[0;36m[--name <name>] [-n <n>] [--empty <empty>] [--verbose] [--enabled] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'opts --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "name=[$name] n=[$n] empty=[$empty] verbose=[$verbose] enabled=[$enabled]"
+}
+
+_completions_opts_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "opts") eval 'array=(
+            "--name:      <name> Who to greet"
+            "-n:          <n> Short option"
+            "--empty:     <empty> EMPTY"
+            "--verbose:   VERBOSE"
+            "--enabled:   ENABLED"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+mixed() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    file=""
+    lines="10"
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Options mixed with positional arguments\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mmixed \\033[0;36m<file> [--lines <lines>] [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mfile      \\033[0m  File to read\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m--lines   \\033[0m  LINES\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            --lines)
+                if [ $# -lt 2 ]; then
+                    printf "\033[1;31mError:\033[0m --lines requires a value\n\n" >&2
+                    printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed \\033[0;36m<file> [--lines <lines>] [-h|--help]\\033[0m\n\n" >&2
+                    printf "Try 'mixed --help' for more information.\n" >&2
+                    return 1
+                fi
+                lines="$2"
+                shift 2
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed \\033[0;36m<file> [--lines <lines>] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'mixed --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        file="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed \\033[0;36m<file> [--lines <lines>] [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'mixed --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$file" ]; then
+        printf "\033[1;31mError:\033[0m file is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36mmixed \\033[0;36m<file> [--lines <lines>] [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'mixed --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "file=[$file] lines=[$lines]"
+}
+
+_completions_mixed_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "mixed") eval 'array=(
+            "--lines:     <lines> LINES"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh opts
+    compdef _complete_zsh mixed
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash opts
+    complete -o default $no_sort -F _complete_bash mixed
+fi
@@ -0,0 +1,322 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+syntax() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Shell syntax in body lines\n\n\\033[1;32mUsage:\\033[0m \\033[1;36msyntax \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mquoted    \\033[0m  Quoted strings still expand variables\n  \\033[1;36mraw       \\033[0m  Raw strings are copied without quotes\n  \\033[1;36msemicolons\\033[0m  Semicolons separate lines\n  \\033[1;36mbraces    \\033[0m  Scripts are copied byte-for-byte\n  \\033[1;36mheredoc   \\033[0m  Heredocs keep their indentation\n  \\033[1;36mchained   \\033[0m  A script is a single line for the prefix\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        quoted) _syntax_quoted_ "$@";;
+        raw) _syntax_raw_ "$@";;
+        semicolons) _syntax_semicolons_ "$@";;
+        braces) _syntax_braces_ "$@";;
+        heredoc) _syntax_heredoc_ "$@";;
+        chained) _syntax_chained_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'syntax --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'syntax --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_syntax_quoted_() {
+    name=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Quoted strings still expand variables\n\n\\033[1;32mUsage:\\033[0m \\033[1;36msyntax quoted \\033[0;36m<name> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mname      \\033[0m  NAME\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax quoted \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'syntax quoted --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        name="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax quoted \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'syntax quoted --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$name" ]; then
+        printf "\033[1;31mError:\033[0m name is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax quoted \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'syntax quoted --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "Hello $name" ".venv"
+}
+
+_syntax_raw_() {
+    name=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Raw strings are copied without quotes\n\n\\033[1;32mUsage:\\033[0m \\033[1;36msyntax raw \\033[0;36m<name> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mname      \\033[0m  NAME\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax raw \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'syntax raw --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        name="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax raw \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'syntax raw --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$name" ]; then
+        printf "\033[1;31mError:\033[0m name is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax raw \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'syntax raw --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo '$name' has ${#name} characters
+}
+
+_syntax_semicolons_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Semicolons separate lines\n\n\\033[1;32mUsage:\\033[0m \\033[1;36msyntax semicolons \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax semicolons \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'syntax semicolons --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax semicolons \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'syntax semicolons --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo one
+    echo two
+}
+
+_syntax_braces_() {
+    name=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Scripts are copied byte-for-byte\n\n\\033[1;32mUsage:\\033[0m \\033[1;36msyntax braces \\033[0;36m<name> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mname      \\033[0m  NAME\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax braces \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'syntax braces --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        name="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax braces \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'syntax braces --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$name" ]; then
+        printf "\033[1;31mError:\033[0m name is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax braces \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'syntax braces --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+greet() { echo "Hello {$1}"; }
+for word in a b; do greet "$word-$name"; done
+}
+
+_syntax_heredoc_() {
+    name=""
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Heredocs keep their indentation\n\n\\033[1;32mUsage:\\033[0m \\033[1;36msyntax heredoc \\033[0;36m<name> [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mname      \\033[0m  NAME\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax heredoc \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'syntax heredoc --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        name="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax heredoc \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'syntax heredoc --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$name" ]; then
+        printf "\033[1;31mError:\033[0m name is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax heredoc \\033[0;36m<name> [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'syntax heredoc --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+cat <<EOF
+  Hello $name
+EOF
+cat <<'EOF'
+Not expanded: $name
+EOF
+}
+
+_syntax_chained_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "A script is a single line for the prefix\n\n\\033[1;32mUsage:\\033[0m \\033[1;36msyntax chained \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax chained \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'syntax chained --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36msyntax chained \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'syntax chained --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    {
+echo first
+false
+    } || return $?
+    echo "not printed" || return $?
+}
+
+_completions_syntax_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "syntax") eval 'array=(
+            "quoted:      Quoted strings still expand variables"
+            "raw:         Raw strings are copied without quotes"
+            "semicolons:  Semicolons separate lines"
+            "braces:      Scripts are copied byte-for-byte"
+            "heredoc:     Heredocs keep their indentation"
+            "chained:     A script is a single line for the prefix"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh syntax
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash syntax
+fi
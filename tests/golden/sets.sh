@@ -0,0 +1,185 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+release() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Release tasks\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mrelease \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mbuild     \\033[0m  Build the release\n  \\033[1;36mpublish   \\033[0m  Publish the release\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        build) _release_build_ "$@";;
+        publish) _release_publish_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mrelease \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'release --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mrelease \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'release --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_release_build_() {
+    target=""
+    env="dev"
+    dry_run=false
+    _pos_count=0
+
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Build the release\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mrelease build \\033[0;36m<target> [--env <env>] [--dry_run] [-h|--help]\\033[0m\n\n\\033[1;32mPositional arguments:\\033[0m\n  \\033[1;36mtarget    \\033[0m  TARGET\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m--env     \\033[0m  Target environment\n  \\033[1;36m--dry_run \\033[0m  Only print what would be done\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            --env)
+                if [ $# -lt 2 ]; then
+                    printf "\033[1;31mError:\033[0m --env requires a value\n\n" >&2
+                    printf "\033[1;32mUsage:\033[0m \\033[1;36mrelease build \\033[0;36m<target> [--env <env>] [--dry_run] [-h|--help]\\033[0m\n\n" >&2
+                    printf "Try 'release build --help' for more information.\n" >&2
+                    return 1
+                fi
+                env="$2"
+                shift 2
+                ;;
+            --dry_run)
+                dry_run=true
+                shift
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mrelease build \\033[0;36m<target> [--env <env>] [--dry_run] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'release build --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                case "$_pos_count" in
+                    0)
+                        target="$1"
+                        _pos_count=$((_pos_count + 1))
+                        shift
+                        ;;
+                    *)
+                        printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                        printf "\033[1;32mUsage:\033[0m \\033[1;36mrelease build \\033[0;36m<target> [--env <env>] [--dry_run] [-h|--help]\\033[0m\n\n" >&2
+                        printf "Try 'release build --help' for more information.\n" >&2
+                        return 1
+                        ;;
+                esac
+                ;;
+        esac
+    done
+
+    if [ -z "$target" ]; then
+        printf "\033[1;31mError:\033[0m target is required\n\n" >&2
+        printf "\033[1;32mUsage:\033[0m \\033[1;36mrelease build \\033[0;36m<target> [--env <env>] [--dry_run] [-h|--help]\\033[0m\n\n" >&2
+        printf "Try 'release build --help' for more information.\n" >&2
+        return 1
+    fi
+    # Execute command
+    echo "build $target for $env (dry run: $dry_run)"
+}
+
+_release_publish_() {
+    env="dev"
+    dry_run=false
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Publish the release\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mrelease publish \\033[0;36m[--env <env>] [--dry_run] [-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m--env     \\033[0m  Target environment\n  \\033[1;36m--dry_run \\033[0m  Only print what would be done\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            --env)
+                if [ $# -lt 2 ]; then
+                    printf "\033[1;31mError:\033[0m --env requires a value\n\n" >&2
+                    printf "\033[1;32mUsage:\033[0m \\033[1;36mrelease publish \\033[0;36m[--env <env>] [--dry_run] [-h|--help]\\033[0m\n\n" >&2
+                    printf "Try 'release publish --help' for more information.\n" >&2
+                    return 1
+                fi
+                env="$2"
+                shift 2
+                ;;
+            --dry_run)
+                dry_run=true
+                shift
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mrelease publish \\033[0;36m[--env <env>] [--dry_run] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'release publish --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mrelease publish \\033[0;36m[--env <env>] [--dry_run] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'release publish --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "publish to $env (dry run: $dry_run)"
+}
+
+_completions_release_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "release") eval 'array=(
+            "build:       Build the release"
+            "publish:     Publish the release"
+            )';;
+        "release build") eval 'array=(
+            "--env:       <env> Target environment"
+            "--dry_run:   Only print what would be done"
+            )';;
+        "release publish") eval 'array=(
+            "--env:       <env> Target environment"
+            "--dry_run:   Only print what would be done"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh release
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash release
+fi
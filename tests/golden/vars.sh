@@ -0,0 +1,141 @@
+
+# Auto-generated CLI shell functions
+# All modifications will be lost when terminal is reloaded
+
+images() {
+    setopt localoptions sh_word_split 2>/dev/null || true
+    subcmd="$1"
+    if [ $# -gt 0 ]; then shift; fi
+    case "$subcmd" in
+        -h|--help)
+            printf "Push images to ghcr.io/acme\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mimages \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n\\033[1;32mCommands:\\033[0m\n  \\033[1;36mpush      \\033[0m  Push an image to ghcr.io/acme\n  \\033[1;36mformat    \\033[0m  Templates of other tools are left as is\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+            return
+            ;;
+        push) _images_push_ "$@";;
+        format) _images_format_ "$@";;
+        -*)
+            printf "\033[1;31mError:\033[0m Unknown option: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mimages \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'images --help' for more information.\n" >&2
+            return 1
+            ;;
+        *)
+            printf "\033[1;31mError:\033[0m Unknown subcommand: $subcmd\n\n" >&2
+            printf "\033[1;32mUsage:\033[0m \\033[1;36mimages \\033[0;36m<subcommand> [-h|--help]\\033[0m\n\n" >&2
+            printf "Try 'images --help' for more information.\n" >&2
+            return 1
+            ;;
+    esac
+}
+
+_images_push_() {
+    tag="latest"
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Push an image to ghcr.io/acme\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mimages push \\033[0;36m[--tag <tag>] [-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m--tag     \\033[0m  Tag of ghcr.io/acme/app\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            --tag)
+                if [ $# -lt 2 ]; then
+                    printf "\033[1;31mError:\033[0m --tag requires a value\n\n" >&2
+                    printf "\033[1;32mUsage:\033[0m \\033[1;36mimages push \\033[0;36m[--tag <tag>] [-h|--help]\\033[0m\n\n" >&2
+                    printf "Try 'images push --help' for more information.\n" >&2
+                    return 1
+                fi
+                tag="$2"
+                shift 2
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mimages push \\033[0;36m[--tag <tag>] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'images push --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mimages push \\033[0;36m[--tag <tag>] [-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'images push --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "docker push ghcr.io/acme/app:$tag"
+}
+
+_images_format_() {
+    while [ $# -gt 0 ]; do
+        case "$1" in
+            -h|--help)
+                printf "Templates of other tools are left as is\n\n\\033[1;32mUsage:\\033[0m \\033[1;36mimages format \\033[0;36m[-h|--help]\\033[0m\n\n\\033[1;32mOptions:\\033[0m\n  \\033[1;36m-h, --help\\033[0m  Show help information\n"
+                return
+                ;;
+            -*)
+                printf "\033[1;31mError:\033[0m Unknown option: $1\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mimages format \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'images format --help' for more information.\n" >&2
+                return 1
+                ;;
+            *)
+                printf "\033[1;31mError:\033[0m Too many arguments\n\n" >&2
+                printf "\033[1;32mUsage:\033[0m \\033[1;36mimages format \\033[0;36m[-h|--help]\\033[0m\n\n" >&2
+                printf "Try 'images format --help' for more information.\n" >&2
+                return 1
+                ;;
+        esac
+    done
+
+    # Execute command
+    echo "docker ps --format {{.Names}}"
+}
+
+_completions_images_() {
+    local -a array
+    local current=$1; shift
+    eval 'local previous=($@)'
+    case "${previous[@]}" in
+        "images") eval 'array=(
+            "push:        Push an image to ghcr.io/acme"
+            "format:      Templates of other tools are left as is"
+            )';;
+        "images push") eval 'array=(
+            "--tag:       <tag> Tag of ghcr.io/acme/app"
+            )';;
+        *) ;;
+    esac
+    eval 'array+=("-h:          Show help information" "--help:      Show help information")'
+    for elem in "${array[@]}"; do
+        if [[ $elem == "$current"* ]]; then echo "$elem"; fi
+    done
+}
+
+_complete_bash() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    eval 'local previous=("${COMP_WORDS[@]:0:COMP_CWORD}")'
+    eval 'raw=($("_completions_${COMP_WORDS[0]}_" "${COMP_WORDS[$COMP_CWORD]}" "${previous[@]}"))'
+    if (( ${#raw[@]} == 1 )); then
+        eval 'raw=( "${raw[0]%%:*}" )'
+    fi
+    eval 'COMPREPLY=( "${raw[@]}" )'
+}
+
+_complete_zsh() {
+    local -a raw trimmed
+    local IFS=$'\n'
+    local previous="${(j: :)words[1,$((CURRENT-1))]}"
+    eval 'raw=($("_completions_${words[1]}_" "" "${previous[@]}"))'
+    if [ -z "$raw" ]; then
+        _default
+    else
+        eval 'for d in "${raw[@]}"; do trimmed+=( "${d%%:*}" ); done'
+        compadd -V $i -- -d raw -- $trimmed
+    fi
+}
+if [ -n "${ZSH_VERSION:-}" ]; then autoload -Uz compinit; compinit
+    compdef _complete_zsh images
+elif [ -n "${BASH_VERSION:-}" ]; then [ "${BASH_VERSINFO[0]}" -lt 4 ] && no_sort="" || no_sort="-o nosort"
+    complete -o default $no_sort -F _complete_bash images
+fi
@@ -0,0 +1,56 @@
+//! Import shell functions and aliases, and check the imported commands behave
+//! like the originals once generated.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn imported_commands_behave_like_the_originals() {
+    let original = common::fixture("dotfiles.sh");
+    let kdl = common::eashy(&["-q", "import", original.to_str().unwrap()]);
+    let imported = Path::new(env!("CARGO_TARGET_TMPDIR")).join("dotfiles.kdl");
+    fs::write(&imported, kdl).unwrap();
+    let script = Path::new(env!("CARGO_TARGET_TMPDIR")).join("dotfiles.sh");
+    fs::write(&script, common::generate(&imported)).unwrap();
+
+    for shell in common::installed_shells() {
+        for command_line in [
+            "greet World Mars",
+            "countdown 3",
+            "check_dir /",
+            "check_dir /missing",
+            "quick fox",
+            "quick -n",
+            "quick a b",
+            "forward -h x",
+            "show_args a b",
+            "hello there",
+        ] {
+            // Aliases are only expanded by default in interactive bash
+            let command_line = format!("shopt -s expand_aliases 2>/dev/null\n{}", command_line);
+            let expected = common::run(shell, &original, &command_line);
+            let output = common::run(shell, &script, &command_line);
+            let context = format!("[{}] {}", shell, command_line);
+            assert_eq!(output.stdout, expected.stdout, "stdout of {}", context);
+            assert_eq!(output.stderr, expected.stderr, "stderr of {}", context);
+            assert_eq!(output.status, expected.status, "status of {}", context);
+        }
+    }
+}
+
+#[test]
+fn import_flags_what_it_cannot_translate() {
+    let original = common::fixture("dotfiles.sh");
+    let output = common::eashy_output(&["import", original.to_str().unwrap()]);
+    assert_eq!(output.status, 0, "{}", output.stderr);
+
+    assert!(output.stderr.contains("skipped 'export EDITOR=vim'"));
+    // Functions reading their arguments get them as they are
+    assert!(
+        output
+            .stdout
+            .contains("(\"Greet someone\") \\\ngreet {\n    raw-args\n")
+    );
+}
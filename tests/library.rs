@@ -0,0 +1,793 @@
+//! Use eashy as a library, without the binary
+
+mod common;
+
+use eashy::{Children, Format, Target};
+use std::fs;
+
+#[test]
+fn generate_matches_the_binary_output() {
+    for name in ["arguments", "nested", "options", "prefixes"] {
+        let commands = eashy::parse_file(common::fixture(&format!("{}.kdl", name))).unwrap();
+        let golden = common::root()
+            .join("tests")
+            .join("golden")
+            .join(format!("{}.sh", name));
+        assert_eq!(
+            eashy::generate(&commands, Target::Shell).unwrap(),
+            fs::read_to_string(golden).unwrap(),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn parse_str_returns_the_command_tree() {
+    let commands = eashy::parse_str(
+        r#"
+        ("Top") top {
+            leaf arg n=1 {
+                echo $arg
+            }
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].description.as_deref(), Some("Top"));
+    let Children::Subcmds(subcommands) = &commands[0].children else {
+        panic!("top should have subcommands");
+    };
+    assert_eq!(subcommands[0].path, ["top", "leaf"]);
+    assert_eq!(subcommands[0].get_mangled_function_name(), "_top_leaf_");
+    assert_eq!(subcommands[0].get_positional_arguments()[0].name, "arg");
+    assert_eq!(subcommands[0].get_optional_arguments()[0].name, "n");
+    assert!(matches!(&subcommands[0].children, Children::Body(lines) if lines == &["echo $arg"]));
+}
+
+#[test]
+fn parse_errors() {
+    assert!(eashy::parse_str("broken {").is_err());
+
+    let error = eashy::parse_str("a {}\na {}").unwrap_err();
+    assert!(error.to_string().contains("command 'a' is defined twice"));
+
+    let error = eashy::parse_file("missing.kdl").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Failed to read KDL file 'missing.kdl'")
+    );
+}
+
+#[test]
+fn builder_generates_like_kdl() {
+    use eashy::{ArgPrefix, Argument, CmdPrefix, Command};
+
+    let built = Command::builder("top")
+        .description("Top level")
+        .subcommand(
+            Command::builder("middle")
+                .description("Middle level")
+                .subcommand(
+                    Command::builder("bottom")
+                        .description("Bottom level")
+                        .arg(Argument::positional("arg"))
+                        .line("echo \"bottom [$arg]\"")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .subcommand(
+            Command::builder("leaf")
+                .description("Sibling leaf")
+                .line("echo \"leaf\"")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let parsed = eashy::parse_file(common::fixture("nested.kdl")).unwrap();
+    assert_eq!(
+        eashy::generate(&[built], Target::Shell).unwrap(),
+        eashy::generate(&parsed, Target::Shell).unwrap()
+    );
+
+    let leaf = Command::builder("copy")
+        .prefix(CmdPrefix::UntilError)
+        .arg(Argument::positional("src").prefix(ArgPrefix::OneMore))
+        .arg(Argument::option("force", false).help("Overwrite files"))
+        .line("cp $src .")
+        .build()
+        .unwrap();
+    assert_eq!(leaf.path, ["copy"]);
+    assert_eq!(leaf.get_optional_arguments()[0].help, "Overwrite files");
+}
+
+#[test]
+fn builder_validates_commands() {
+    use eashy::{Argument, Command};
+
+    let error = Command::builder("bad")
+        .arg(Argument::positional("not-a-variable"))
+        .build()
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("not a valid shell variable name")
+    );
+
+    let leaf = Command::builder("leaf").build().unwrap();
+    let error = Command::builder("mixed")
+        .subcommand(leaf.clone())
+        .subcommand(leaf)
+        .build()
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("subcommand 'leaf' is defined twice")
+    );
+
+    let mixed = Command::builder("mixed")
+        .subcommand(Command::builder("leaf").build().unwrap())
+        .line("echo")
+        .build()
+        .unwrap();
+    assert!(matches!(mixed.children, Children::Subcmds(_)));
+    assert_eq!(mixed.default_body, ["echo"]);
+}
+
+#[test]
+fn other_formats_generate_like_kdl() {
+    let golden = fs::read_to_string(common::root().join("tests/golden/options.sh")).unwrap();
+    for extension in ["json", "yaml", "toml"] {
+        let commands =
+            eashy::parse_file(common::fixture(&format!("options.{}", extension))).unwrap();
+        assert_eq!(
+            eashy::generate(&commands, Target::Shell).unwrap(),
+            golden,
+            "{}",
+            extension
+        );
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    let commands = eashy::parse_file(common::fixture("nested.kdl")).unwrap();
+    let json = serde_json::to_string(&commands).unwrap();
+    let parsed =
+        eashy::parse_str_as(&format!(r#"{{"commands": {}}}"#, json), Format::Json).unwrap();
+
+    assert_eq!(
+        eashy::generate(&parsed, Target::Shell).unwrap(),
+        eashy::generate(&commands, Target::Shell).unwrap()
+    );
+    let Children::Subcmds(subcommands) = &parsed[0].children else {
+        panic!("top should have subcommands");
+    };
+    assert_eq!(subcommands[0].path, ["top", "middle"]);
+}
+
+#[test]
+fn other_formats_mixed_commands() {
+    let commands = eashy::parse_str_as(
+        r#"{"commands": [{"name": "a", "body": ["true"], "subcommands": [{"name": "b"}]}]}"#,
+        Format::Json,
+    )
+    .unwrap();
+    assert_eq!(commands[0].default_body, ["true"]);
+    let json = serde_json::to_string(&commands).unwrap();
+    assert!(json.contains(r#""body":["true"]"#));
+}
+
+#[test]
+fn other_formats_errors() {
+    let error =
+        eashy::parse_str_as("commands:\n  - name: a\n  - name: a\n", Format::Yaml).unwrap_err();
+    assert!(error.to_string().contains("command 'a' is defined twice"));
+
+    let error = eashy::parse_str_as(
+        "[[commands]]\nname = \"a\"\nbody = \"true\"\n",
+        Format::Toml,
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("invalid type"));
+}
+
+#[test]
+fn dump_resolves_the_command_tree() {
+    let nested = common::fixture("nested.kdl");
+    let dump: serde_json::Value =
+        serde_json::from_str(&common::eashy(&["-f", nested.to_str().unwrap(), "dump"])).unwrap();
+
+    let top = &dump["commands"][0];
+    assert_eq!(top["function"], "top");
+    assert_eq!(top["location"]["file"], nested.to_str().unwrap());
+    assert_eq!(top["location"]["line"], 3);
+    assert_eq!(top["location"]["column"], 1);
+
+    let bottom = &top["subcommands"][0]["subcommands"][0];
+    assert_eq!(
+        bottom["path"],
+        serde_json::json!(["top", "middle", "bottom"])
+    );
+    assert_eq!(bottom["function"], "_top_middle_bottom_");
+    assert_eq!(bottom["description"], "Bottom level");
+    assert_eq!(bottom["usage"], "top middle bottom <arg> [-h|--help]");
+    assert_eq!(bottom["arguments"][0]["kind"], "positional");
+    assert_eq!(bottom["body"][0], "echo \"bottom [$arg]\"");
+    assert_eq!(bottom["location"]["line"], 7);
+    assert_eq!(bottom["location"]["column"], 9);
+
+    let options = eashy::parse_str("opts name=\"world\" v=#false {\n    echo ok\n}").unwrap();
+    let dump: serde_json::Value =
+        serde_json::from_str(&eashy::generate(&options, Target::Json).unwrap()).unwrap();
+    let arguments = &dump["commands"][0]["arguments"];
+    assert_eq!(arguments[0]["kind"], "option");
+    assert_eq!(arguments[0]["flag"], "--name");
+    assert_eq!(arguments[0]["default"], "world");
+    assert_eq!(arguments[1]["kind"], "flag");
+    assert_eq!(arguments[1]["flag"], "-v");
+    assert_eq!(arguments[1]["default"], false);
+    assert!(dump["commands"][0]["location"]["file"].is_null());
+}
+
+#[test]
+fn tasks_are_imported_as_subcommands() {
+    let commands = eashy::parse_file(common::fixture("tasks/tasks.kdl")).unwrap();
+    let Children::Subcmds(subcommands) = &commands[0].children else {
+        panic!("proj should have subcommands");
+    };
+    let names: Vec<&str> = subcommands.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["version", "build", "test", "clean", "site"]);
+    assert_eq!(subcommands[1].path, ["proj", "build"]);
+    assert!(subcommands[1].raw_args);
+    assert!(subcommands[1].arguments.is_empty());
+
+    let Children::Subcmds(site) = &subcommands[4].children else {
+        panic!("site should have subcommands");
+    };
+    let tasks: Vec<(&str, Option<&str>)> = site
+        .iter()
+        .map(|c| (c.name.as_str(), c.description.as_deref()))
+        .collect();
+    assert_eq!(
+        tasks,
+        [
+            ("serve", Some("Serve the site on localhost")),
+            ("deploy", Some("Deploy the site")),
+            // In the order of package.json
+            ("lint", Some("eslint .")),
+            ("format", Some("prettier --write .")),
+        ]
+    );
+    let Children::Body(lines) = &site[0].children else {
+        panic!("serve should have a body");
+    };
+    let justfile = common::fixture("tasks/justfile");
+    assert_eq!(
+        lines[0],
+        format!(
+            "just --justfile '{}' --working-directory '{}' 'serve' \"$@\"",
+            justfile.display(),
+            justfile.parent().unwrap().display()
+        )
+    );
+    assert_eq!(site[0].location.as_ref().unwrap().line, 10);
+
+    let error = eashy::parse_str("proj { from-make \"missing/Makefile\"; }").unwrap_err();
+    assert!(error.to_string().contains("'proj': failed to read"));
+
+    let error = eashy::parse_str("wrap x { raw-args; echo; }").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("'wrap': a command with raw arguments cannot declare arguments")
+    );
+}
+
+#[test]
+fn body_lines_map_entries_to_shell_words() {
+    let commands = eashy::parse_str(
+        r####"
+        lines {
+            cd ".venv" && ls 0x10 #true #null
+            echo "a \"quoted\" \\ word" #"[ -d "$dir" ]"#
+            script "log.txt"
+            script #"test -f "$1" && echo {} ; "#
+        }
+        "####,
+    )
+    .unwrap();
+    let Children::Body(lines) = &commands[0].children else {
+        panic!("lines should have a body");
+    };
+    assert_eq!(
+        lines,
+        &[
+            r#"cd ".venv" && ls 0x10 true null"#,
+            r#"echo "a \"quoted\" \\ word" [ -d "$dir" ]"#,
+            r#"script "log.txt""#,
+            r#"test -f "$1" && echo {} ; "#,
+        ]
+    );
+}
+
+#[test]
+fn body_lines_keep_properties() {
+    let (commands, warnings) = eashy::parse_source_with_warnings(
+        r#"
+        run {
+            docker run --env FOO=bar x="a b" image
+            echo (u8)5 #null
+            ("Not a description") echo
+        }
+        "#,
+        "commands.kdl",
+    )
+    .unwrap();
+    let Children::Body(lines) = &commands[0].children else {
+        panic!("run should have a body");
+    };
+    assert_eq!(
+        lines,
+        &[
+            r#"docker run --env FOO=bar x="a b" image"#,
+            "echo 5 null",
+            "echo"
+        ]
+    );
+    assert_eq!(
+        warnings,
+        [
+            "'run', line 4: type annotation '(u8)' of '5' is ignored",
+            "'run', line 4: '#null' has no shell equivalent, written as 'null'",
+            "'run', line 5: description '(Not a description)' of body line 'echo' is ignored, \
+             declare subcommands with `cmd echo`",
+        ]
+    );
+}
+
+#[test]
+fn cmd_declares_subcommands() {
+    let (commands, warnings) = eashy::parse_source_with_warnings(
+        r#"
+        git-flow {
+            cmd status
+            ("Show the log") cmd log count { git log -n $count; }
+        }
+        top {
+            leaf
+            sub { echo "sub"; }
+        }
+        "#,
+        "commands.kdl",
+    )
+    .unwrap();
+    let Children::Subcmds(subcommands) = &commands[0].children else {
+        panic!("git-flow should have subcommands");
+    };
+    assert_eq!(subcommands[0].name, "status");
+    assert!(matches!(&subcommands[0].children, Children::Body(lines) if lines.is_empty()));
+    assert_eq!(subcommands[1].path, ["git-flow", "log"]);
+    assert_eq!(subcommands[1].description.as_deref(), Some("Show the log"));
+    assert_eq!(subcommands[1].get_positional_arguments()[0].name, "count");
+
+    assert_eq!(
+        warnings,
+        [
+            "'top', line 7: 'leaf' has no block, so it is a subcommand doing nothing: \
+          declare it with `cmd leaf`, or move it to a `run` block if it is a shell line"
+        ]
+    );
+}
+
+#[test]
+fn settings_blocks_warn_among_subcommands() {
+    let (commands, warnings) = eashy::parse_source_with_warnings(
+        r#"
+        dk {
+            run { echo running; }
+            finally { echo done; }
+            cmd env { printenv; }
+            build { echo build; }
+        }
+        leaf {
+            env { A "1"; }
+            echo "$A"
+        }
+        old {
+            run { up { echo up; }; }
+            before { echo before; }
+            status { echo status; }
+        }
+        "#,
+        "commands.kdl",
+    )
+    .unwrap();
+    assert_eq!(commands[0].default_body, ["echo running"]);
+    let Children::Subcmds(subcommands) = &commands[0].children else {
+        panic!("dk should have subcommands");
+    };
+    assert_eq!(subcommands[0].name, "env");
+    assert_eq!(commands[1].env, [("A".to_string(), "1".to_string())]);
+
+    // Only blocks holding blocks, like the subcommands they used to be
+    assert_eq!(
+        warnings,
+        [
+            "'old', line 13: 'run' block is the default body of the command, not a subcommand, \
+          so its 'up' block is ignored: declare it with `cmd run` if it is one"
+        ]
+    );
+}
+
+#[test]
+fn global_options_are_not_shadowed() {
+    let error =
+        eashy::parse_str("top verbose=#false { sub { leaf verbose { echo; }; }; }").unwrap_err();
+    assert!(
+        error.to_string().contains(
+            "'top sub leaf': argument 'verbose' conflicts with the global option of 'top'"
+        )
+    );
+}
+
+#[test]
+fn argument_sets_are_expanded() {
+    let (commands, warnings) = eashy::parse_source_with_warnings(
+        r#"
+        deploy target { use "common" "extra"; echo; }
+        args "common" env="dev" dry_run=#false
+        args "extra" force=#false
+        args "unused" x=1
+        "#,
+        "commands.kdl",
+    )
+    .unwrap();
+    let names: Vec<&str> = commands[0]
+        .arguments
+        .iter()
+        .map(|arg| arg.name.as_str())
+        .collect();
+    assert_eq!(names, ["target", "env", "dry_run", "force"]);
+    assert_eq!(commands.len(), 1);
+    assert_eq!(warnings, ["argument set 'unused' is never used"]);
+
+    let error = eashy::parse_str(r#"a { use "missing"; echo; }"#).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("'a': unknown argument set 'missing'")
+    );
+
+    let error = eashy::parse_str(r#"args "s" x=1; args "s" y=2"#).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("argument set 's' is defined twice")
+    );
+}
+
+#[test]
+fn variables_are_interpolated() {
+    let (commands, warnings) = eashy::parse_source_with_warnings(
+        r#"
+        show dir="{{env.PATH}}" {
+            echo "{{name}} {{ env.EASHY_UNSET_VARIABLE }} {{missing}}"
+        }
+        let name="eashy"
+        "#,
+        "commands.kdl",
+    )
+    .unwrap();
+    let path = std::env::var("PATH").unwrap();
+    assert_eq!(
+        commands[0].get_optional_arguments()[0].option,
+        Some(kdl::KdlValue::String(path))
+    );
+    assert!(matches!(
+        &commands[0].children,
+        Children::Body(lines)
+            if lines == &[r#"echo "eashy {{ env.EASHY_UNSET_VARIABLE }} {{missing}}""#]
+    ));
+    assert_eq!(
+        warnings,
+        [
+            "'show', line 3: environment variable 'EASHY_UNSET_VARIABLE' is not set, \
+             '{{ env.EASHY_UNSET_VARIABLE }}' is left as is",
+            "'show', line 3: unknown variable '{{missing}}' is left as is",
+        ]
+    );
+
+    // Unterminated and escaped references are kept as is
+    let commands = eashy::parse_str(
+        r##"
+        a { echo "abc {{ def"; }
+        b { script #"echo "\{{name}} {{name}}" {{"#; }
+        let name="eashy"
+        "##,
+    )
+    .unwrap();
+    assert!(matches!(
+        &commands[0].children,
+        Children::Body(lines) if lines == &[r#"echo "abc {{ def""#]
+    ));
+    assert!(matches!(
+        &commands[1].children,
+        Children::Body(lines) if lines == &[r#"echo "{{name}} eashy" {{"#]
+    ));
+
+    // Quoted strings are escaped and quoted after interpolation
+    let commands = eashy::parse_str(
+        r##"
+        let quote="say \"hi\" \\o/"
+        a { echo "\\{{quote}}" "{{quote}}" #"'{{quote}}'"#; }
+        "##,
+    )
+    .unwrap();
+    assert!(matches!(
+        &commands[0].children,
+        Children::Body(lines)
+            if lines == &[r##"echo "{{quote}}" "say \"hi\" \\o/" 'say "hi" \o/'"##]
+    ));
+}
+
+#[test]
+fn conditions_select_commands() {
+    let kdl = format!(
+        r#"
+        kept {{ when has="sh" os="{}"; echo; }}
+        dropped {{ when has="eashy-missing-program"; echo; }}
+        group {{
+            when has="kubectl" runtime=#true
+            dropped {{ when has="eashy-missing-program"; echo; }}
+            kept {{ echo; }}
+        }}
+        "#,
+        std::env::consts::OS
+    );
+    let commands = eashy::parse_str(&kdl).unwrap();
+    let names: Vec<&str> = commands.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["kept", "group"]);
+    assert!(commands[0].requires.is_empty());
+    assert_eq!(
+        commands[1].requires,
+        [eashy::Condition::Has("kubectl".to_string())]
+    );
+    assert!(matches!(
+        &commands[1].children,
+        Children::Subcmds(subcommands) if subcommands.len() == 1
+    ));
+
+    let json = eashy::generate(&commands, Target::Json).unwrap();
+    assert!(json.contains(r#""has": "kubectl""#));
+    let commands = eashy::parse_str_as(
+        r#"{"commands": [{"name": "a", "requires": [{"os": "linux"}]}]}"#,
+        Format::Json,
+    )
+    .unwrap();
+    assert_eq!(
+        commands[0].requires,
+        [eashy::Condition::Os("linux".to_string())]
+    );
+
+    let error = eashy::parse_str(r#"a { when user="root"; echo; }"#).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("'a': unknown condition 'user', expected os, has or host")
+    );
+    let error = eashy::parse_str(r#"a { when os="beos"; echo; }"#).unwrap_err();
+    assert!(error.to_string().contains("'a': unknown os 'beos'"));
+
+    // Conditions from other formats are checked like the KDL ones
+    let error = eashy::parse_str_as(
+        r#"{"commands": [{"name": "a", "requires": [{"host": "x) echo pwned ;; y"}, {"os": "beos"}]}]}"#,
+        Format::Json,
+    )
+    .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("'a': invalid host pattern 'x) echo pwned ;; y'")
+    );
+    assert!(error.to_string().contains("'a': unknown os 'beos'"));
+
+    // Program names cannot run commands in the error message of the check
+    let error =
+        eashy::parse_str(r#"a { when has="x$(touch pwned)" runtime=#true; echo; }"#).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("'a': invalid program name 'x$(touch pwned)'")
+    );
+    let error = eashy::parse_str_as(
+        r#"{"commands": [{"name": "a", "requires": [{"has": "x`id`"}]}]}"#,
+        Format::Json,
+    )
+    .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("'a': invalid program name 'x`id`'")
+    );
+}
+
+#[test]
+fn hooks_are_parsed_and_inherited() {
+    let commands = eashy::parse_str(
+        r#"
+        group {
+            before { cd "$HOME"; }
+            finally { rm -rf "/tmp/work"; }
+            leaf {
+                before { mkdir -p "/tmp/work"; }
+                on-error { echo "failed"; }
+                echo "/tmp/work"
+            }
+            ("A subcommand named after a hook") \
+            after { echo "subcommand"; }
+        }
+        "#,
+    )
+    .unwrap();
+    let group = &commands[0];
+    assert_eq!(group.hooks.before, [r#"cd "$HOME""#]);
+    assert_eq!(group.hooks.finally, [r#"rm -rf "/tmp/work""#]);
+    let Children::Subcmds(subcommands) = &group.children else {
+        panic!("group should have subcommands");
+    };
+    assert_eq!(subcommands[0].hooks.before, [r#"mkdir -p "/tmp/work""#]);
+    assert_eq!(subcommands[0].hooks.on_error, [r#"echo "failed""#]);
+    assert_eq!(subcommands[1].name, "after");
+
+    let inherited = subcommands[0].hooks.inherit(&group.hooks);
+    assert_eq!(
+        inherited.before,
+        [r#"cd "$HOME""#, r#"mkdir -p "/tmp/work""#]
+    );
+    assert_eq!(inherited.finally, [r#"rm -rf "/tmp/work""#]);
+
+    let script = eashy::generate(&commands, Target::Shell).unwrap();
+    assert!(script.contains("_group_leaf__body_ || { _status=$?; break; }"));
+    assert!(script.contains("_group_after__body_() {"));
+
+    let json = eashy::generate(&commands, Target::Json).unwrap();
+    let document: eashy::Command = serde_json::from_str(
+        r#"{"name": "a", "hooks": {"on_error": ["echo failed"]}, "body": ["false"]}"#,
+    )
+    .unwrap();
+    assert_eq!(document.hooks.on_error, ["echo failed"]);
+    assert!(json.contains(r#""finally": ["#));
+}
+
+#[test]
+fn working_directory_and_environment_are_parsed() {
+    let commands = eashy::parse_source(
+        r#"
+        group {
+            cwd "git-root"
+            env { B "2"; A 1; }
+            leaf { cwd "sub"; echo; }
+            shell { affects-caller; env { C "{{name}}"; }; echo; }
+        }
+        let name="eashy"
+        "#,
+        "/project/commands.kdl",
+    )
+    .unwrap();
+    let group = &commands[0];
+    assert_eq!(group.cwd.as_deref(), Some("git-root"));
+    assert_eq!(
+        group.env,
+        [
+            ("B".to_string(), "2".to_string()),
+            ("A".to_string(), "1".to_string())
+        ]
+    );
+    let Children::Subcmds(subcommands) = &group.children else {
+        panic!("group should have subcommands");
+    };
+    assert_eq!(subcommands[0].cwd.as_deref(), Some("/project/sub"));
+    assert!(!subcommands[0].affects_caller);
+    assert!(subcommands[1].affects_caller);
+    assert_eq!(subcommands[1].env, [("C".to_string(), "eashy".to_string())]);
+
+    // Environment variables keep their order in other formats
+    let json = serde_json::to_string(&commands).unwrap();
+    assert!(json.contains(r#""env":{"B":"2","A":"1"}"#));
+    let parsed: Vec<eashy::Command> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed[0].env, group.env);
+
+    let script = eashy::generate(&commands, Target::Shell).unwrap();
+    assert!(script.contains("        cd '/project/sub' || return $?\n"));
+    assert!(script.contains("        export B=\"2\"\n"));
+
+    let error = eashy::parse_str(r#"a { env { "A B" "c"; }; echo; }"#).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("'a': invalid environment variable name 'A B'")
+    );
+    let error = eashy::parse_str(r#"a { env { A; }; echo; }"#).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("'a': environment variable 'A' takes a single value")
+    );
+
+    // Home directories are expanded by the shell
+    let commands = eashy::parse_source(
+        r#"a { cwd "~/notes"; echo; }; b { cwd "$HOME/notes"; echo; }"#,
+        "/project/commands.kdl",
+    )
+    .unwrap();
+    assert_eq!(commands[0].cwd.as_deref(), Some("~/notes"));
+    assert_eq!(commands[1].cwd.as_deref(), Some("$HOME/notes"));
+    let script = eashy::generate(&commands, Target::Shell).unwrap();
+    assert!(script.contains(r#"cd "$HOME"'/notes' || return $?"#));
+    assert!(script.contains(r#"cd "$HOME/notes" || return $?"#));
+
+    // Other formats are resolved and checked like KDL
+    let commands = eashy::parse_source(
+        r#"{"commands": [
+            {"name": "a", "cwd": "kdl-dir", "body": ["echo"]},
+            {"name": "b", "cwd": "sub", "body": ["echo"]}
+        ]}"#,
+        "/project/commands.json",
+    )
+    .unwrap();
+    assert_eq!(commands[0].cwd.as_deref(), Some("/project"));
+    assert_eq!(commands[1].cwd.as_deref(), Some("/project/sub"));
+
+    // Directories can use variables like the environment variables
+    let commands = eashy::parse_source(
+        r#"let root="/srv/app"; a { cwd "{{root}}/sub"; echo; }"#,
+        "/project/commands.kdl",
+    )
+    .unwrap();
+    assert_eq!(commands[0].cwd.as_deref(), Some("/srv/app/sub"));
+
+    // A bare file name is in the current directory
+    let commands = eashy::parse_source(
+        r#"a { cwd "kdl-dir"; echo; }; b { cwd "sub"; echo; }"#,
+        "commands.kdl",
+    )
+    .unwrap();
+    let current_dir = std::env::current_dir().unwrap();
+    assert_eq!(
+        commands[0].cwd.as_deref(),
+        Some(current_dir.to_str().unwrap())
+    );
+    assert_eq!(
+        commands[1].cwd.as_deref(),
+        Some(current_dir.join("sub").to_str().unwrap())
+    );
+    let error = eashy::parse_str_as(
+        r#"{"commands": [{"name": "a", "env": {"BAD-NAME": "v"}, "body": ["echo"]}]}"#,
+        Format::Json,
+    )
+    .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("'a': invalid environment variable name 'BAD-NAME'")
+    );
+    let error = eashy::Command::builder("a")
+        .env("BAD-NAME", "v")
+        .build()
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("invalid environment variable name")
+    );
+}